@@ -1,8 +1,12 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use iter_num_tools::grid_space;
+use iter_num_tools::{grid_space, grid_step};
 
-fn bench(i: impl Iterator<Item = [f64; 2]>) -> f64 {
-    black_box(black_box(i).map(|[a, b]| a + b).sum())
+fn bench<const N: usize>(i: impl Iterator<Item = [f64; N]>) -> f64 {
+    black_box(black_box(i).map(|x| x.iter().sum::<f64>()).sum())
+}
+
+fn bench_int<const N: usize>(i: impl Iterator<Item = [i32; N]>) -> i32 {
+    black_box(black_box(i).map(|x| x.iter().sum::<i32>()).sum())
 }
 
 pub fn bench_grid_space(c: &mut Criterion) {
@@ -12,8 +16,34 @@ pub fn bench_grid_space(c: &mut Criterion) {
         b.iter(|| bench(grid_space([1.0, 1.0]..=[100.0, 100.0], 200)))
     });
 
+    group.bench_function("gridspace 3d [1.0, 100.0] x40 (iter-num-tools)", |b| {
+        b.iter(|| bench(grid_space([1.0; 3]..=[100.0; 3], 40)))
+    });
+
+    group.bench_function("gridspace 4d [1.0, 100.0] x16 (iter-num-tools)", |b| {
+        b.iter(|| bench(grid_space([1.0; 4]..=[100.0; 4], 16)))
+    });
+
+    group.bench_function("gridspace 4d [1.0, 100.0] x16 rev (iter-num-tools)", |b| {
+        b.iter(|| bench(grid_space([1.0; 4]..=[100.0; 4], 16).rev()))
+    });
+
+    group.finish();
+}
+
+pub fn bench_grid_step(c: &mut Criterion) {
+    let mut group = c.benchmark_group("GridStep");
+
+    group.bench_function("gridstep 3d [0, 40) (iter-num-tools)", |b| {
+        b.iter(|| bench_int(grid_step([0; 3]..[40; 3])))
+    });
+
+    group.bench_function("gridstep 4d [0, 16) (iter-num-tools)", |b| {
+        b.iter(|| bench_int(grid_step([0; 4]..[16; 4])))
+    });
+
     group.finish();
 }
 
-criterion_group!(benches, bench_grid_space);
+criterion_group!(benches, bench_grid_space, bench_grid_step);
 criterion_main!(benches);
@@ -1,5 +1,8 @@
-use crate::linspace::{IntoLinSpace, LinSpace, LinearInterpolation};
-use core::ops::Range;
+use crate::{
+    error::{is_finite, SpaceError},
+    linspace::{IntoLinSpace, LinSpace, LinearInterpolation},
+};
+use core::ops::{Range, RangeInclusive};
 use num_traits::real::Real;
 
 /// [`Iterator`] returned by [`arange`]
@@ -16,6 +19,18 @@ pub type IntoArange<T> = IntoLinSpace<T>;
 ///
 /// let it = arange(0.0..2.0, 0.5);
 /// assert!(it.eq(vec![0.0, 0.5, 1.0, 1.5]));
+///
+/// // negative steps count down
+/// let it = arange(2.0..0.0, -0.5);
+/// assert!(it.eq(vec![2.0, 1.5, 1.0, 0.5]));
+///
+/// // steps that move away from the end produce an empty space
+/// let it = arange(0.0..2.0, -0.5);
+/// assert_eq!(it.len(), 0);
+///
+/// // values within rounding error of the end are still excluded
+/// let it = arange(0.1..0.4, 0.1);
+/// assert_eq!(it.len(), 3);
 /// ```
 pub fn arange<R, F>(range: R, step: F) -> Arange<R::Item>
 where
@@ -24,12 +39,72 @@ where
     range.into_arange(step).into_space()
 }
 
+/// Create a new iterator over the range, stepping by `step` each time,
+/// returning an error instead of panicking if the space is invalid
+///
+/// ```
+/// use iter_num_tools::{try_arange, SpaceError};
+///
+/// let it = try_arange(0.0..2.0, 0.5).unwrap();
+/// assert!(it.eq(vec![0.0, 0.5, 1.0, 1.5]));
+///
+/// assert_eq!(try_arange(0.0..2.0, 0.0).unwrap_err(), SpaceError::ZeroStep);
+/// assert_eq!(try_arange(0.0..2.0, -0.5).unwrap_err(), SpaceError::WrongSignStep);
+/// ```
+pub fn try_arange<R, F>(range: R, step: F) -> Result<Arange<R::Item>, SpaceError>
+where
+    R: ToArange<F>,
+{
+    range.try_into_arange(step).map(IntoArange::into_space)
+}
+
+/// How close the end of a [`RangeInclusive`] must be to a step boundary
+/// for [`arange`] to include it
+///
+/// ```
+/// use iter_num_tools::{arange, Tolerance};
+///
+/// // 2.0 is within a few ulps of 20 steps of 0.1
+/// let it = arange(0.0..=2.0, (0.1, Tolerance::Ulps(4)));
+/// assert_eq!(it.len(), 21);
+/// assert_eq!(it.last(), Some(2.0));
+///
+/// // 2.05 is half a step away from a boundary, so it is not included
+/// let it = arange(0.0..=2.05, (0.1, Tolerance::Relative(0.01)));
+/// assert_eq!(it.len(), 21);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Tolerance<F> {
+    /// The end may be up to this fraction of a step away from a step boundary
+    Relative(F),
+    /// The end may be up to this many units in the last place (of the larger bound) away from a step boundary
+    Ulps(u32),
+}
+
+impl<F: Real> Tolerance<F> {
+    /// Whether `end` is close enough to the step boundary `start + n * step`
+    fn within(self, start: F, end: F, n: F, step: F) -> bool {
+        let diff = (end - (start + n * step)).abs();
+        match self {
+            Tolerance::Relative(rel) => diff <= rel * step.abs(),
+            Tolerance::Ulps(ulps) => {
+                // epsilon scaled by the magnitude is at least one ulp.
+                // the boundary is computed from start, so it carries errors relative to both bounds
+                let ulp = F::epsilon() * start.abs().max(end.abs());
+                diff <= ulp * F::from(ulps).unwrap()
+            }
+        }
+    }
+}
+
 /// Helper trait for [`arange`]
 pub trait ToArange<S> {
     /// The item that this is a arange space over
     type Item;
     /// Create the arange space
     fn into_arange(self, step: S) -> IntoArange<Self::Item>;
+    /// Create the arange space, checking that the range and step are valid
+    fn try_into_arange(self, step: S) -> Result<IntoArange<Self::Item>, SpaceError>;
 }
 
 impl<F: Real> ToArange<F> for Range<F> {
@@ -38,15 +113,125 @@ impl<F: Real> ToArange<F> for Range<F> {
     fn into_arange(self, step: F) -> IntoArange<Self::Item> {
         let Range { start, end } = self;
 
-        IntoArange::new(
-            ((end - start) / step).ceil().to_usize().unwrap(),
-            LinearInterpolation { start, step },
-        )
+        // a step that moves away from the end never reaches it, so the space is empty
+        let len = exclusive_arange_len(start, end, step).max(F::zero());
+
+        IntoArange::new(len.to_usize().unwrap(), stepping(start, end, step))
+    }
+
+    fn try_into_arange(self, step: F) -> Result<IntoArange<Self::Item>, SpaceError> {
+        let Range { start, end } = self;
+        check_arange(start, end, step)?;
+
+        let len = exclusive_arange_len(start, end, step);
+        if len.is_sign_negative() && !len.is_zero() {
+            return Err(SpaceError::WrongSignStep);
+        }
+
+        Ok(IntoArange::new(
+            len.to_usize().ok_or(SpaceError::CountOverflow)?,
+            stepping(start, end, step),
+        ))
+    }
+}
+
+impl<F: Real> ToArange<(F, Tolerance<F>)> for RangeInclusive<F> {
+    type Item = F;
+
+    fn into_arange(self, (step, tol): (F, Tolerance<F>)) -> IntoArange<Self::Item> {
+        let (start, end) = self.into_inner();
+
+        // a step that moves away from the end never reaches it, so the space is empty
+        let (len, interpolate) = inclusive_arange(start, end, step, tol);
+        let len = len.max(F::zero());
+
+        IntoArange::new(len.to_usize().unwrap(), interpolate)
+    }
+
+    fn try_into_arange(
+        self,
+        (step, tol): (F, Tolerance<F>),
+    ) -> Result<IntoArange<Self::Item>, SpaceError> {
+        let (start, end) = self.into_inner();
+        check_arange(start, end, step)?;
+
+        let (len, interpolate) = inclusive_arange(start, end, step, tol);
+        if len < F::one() {
+            return Err(SpaceError::WrongSignStep);
+        }
+
+        Ok(IntoArange::new(
+            len.to_usize().ok_or(SpaceError::CountOverflow)?,
+            interpolate,
+        ))
+    }
+}
+
+fn check_arange<F: Real>(start: F, end: F, step: F) -> Result<(), SpaceError> {
+    if !is_finite(start) || !is_finite(end) || !is_finite(step) {
+        Err(SpaceError::NonFiniteBound)
+    } else if step.is_zero() {
+        Err(SpaceError::ZeroStep)
+    } else {
+        Ok(())
+    }
+}
+
+/// How far from a step boundary the end of an exclusive range can be
+/// while still being treated as landing on that boundary
+const EXCLUSIVE_END_ULPS: u32 = 4;
+
+/// Counts the values in an exclusive arange. Rounding error in `(end - start) / step` can
+/// leave the count just above a whole number, which would yield an extra value that is
+/// approximately `end`. So if `end` is within a few ulps of a step boundary, it is excluded.
+fn exclusive_arange_len<F: Real>(start: F, end: F, step: F) -> F {
+    let steps = (end - start) / step;
+    let nearest = steps.round();
+    if Tolerance::Ulps(EXCLUSIVE_END_ULPS).within(start, end, nearest, step) {
+        nearest
+    } else {
+        steps.ceil()
+    }
+}
+
+/// Steps from `start` by `step`, without ever reaching `end`
+fn stepping<F>(start: F, end: F, step: F) -> LinearInterpolation<F> {
+    LinearInterpolation {
+        start,
+        step,
+        end,
+        end_index: None,
+        from_end: false,
+        span: None,
+    }
+}
+
+/// Counts the values in an inclusive arange. If `end` is within tolerance of a step boundary,
+/// it is yielded exactly in place of that boundary.
+fn inclusive_arange<F: Real>(
+    start: F,
+    end: F,
+    step: F,
+    tol: Tolerance<F>,
+) -> (F, LinearInterpolation<F>) {
+    let steps = (end - start) / step;
+    let nearest = steps.round();
+    if tol.within(start, end, nearest, step) {
+        // only the boundary itself is replaced, the values before it still step from `start`
+        let interpolate = LinearInterpolation {
+            end_index: nearest.to_usize(),
+            ..stepping(start, end, step)
+        };
+        (nearest + F::one(), interpolate)
+    } else {
+        (steps.floor() + F::one(), stepping(start, end, step))
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::check_double_ended_iter;
+
     use super::*;
 
     #[test]
@@ -54,4 +239,197 @@ mod tests {
         let it = arange(0.0..2.0, 0.5);
         assert!(it.eq(vec![0.0, 0.5, 1.0, 1.5]));
     }
+
+    /// common decimal steps, as `num / den`
+    const DECIMAL_STEPS: [(u32, u32); 8] = [
+        (1, 10),
+        (2, 10),
+        (3, 10),
+        (1, 4),
+        (1, 20),
+        (1, 100),
+        (3, 100),
+        (1, 1000),
+    ];
+
+    fn check_decimal_steps<F: Real + crate::LinSpaceNum + core::fmt::Display>() {
+        let decimal = |n: u32, den: u32| F::from(n).unwrap() / F::from(den).unwrap();
+        for (num, den) in DECIMAL_STEPS {
+            let step = decimal(num, den);
+            for i in 0..5 {
+                for k in 1..200 {
+                    let start = decimal(i * num, den);
+                    let end = decimal((i + k) * num, den);
+
+                    let it = arange(start..end, step);
+                    assert_eq!(it.len(), k as usize, "arange({start}..{end}, {step})");
+                    assert!(it.last().unwrap() < end, "arange({start}..{end}, {step})");
+
+                    let it = arange(end..start, -step);
+                    assert_eq!(it.len(), k as usize, "arange({end}..{start}, -{step})");
+                    assert!(
+                        it.last().unwrap() > start,
+                        "arange({end}..{start}, -{step})"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_arange_decimal_steps_f64() {
+        check_decimal_steps::<f64>();
+    }
+
+    #[test]
+    fn test_arange_decimal_steps_f32() {
+        check_decimal_steps::<f32>();
+    }
+
+    #[test]
+    fn test_arange_descending() {
+        check_double_ended_iter(arange(5.0..0.0, -1.0), [5.0, 4.0, 3.0, 2.0, 1.0]);
+        check_double_ended_iter(arange(1.0..-1.0, -0.5), [1.0, 0.5, 0.0, -0.5]);
+        check_double_ended_iter(arange(2.0..0.4, -0.5), [2.0, 1.5, 1.0, 0.5]);
+    }
+
+    #[test]
+    fn test_arange_wrong_sign() {
+        assert_eq!(arange(0.0..2.0, -0.5).len(), 0);
+        assert_eq!(arange(2.0..0.0, 0.5).len(), 0);
+        assert_eq!(arange(2.0..2.0, -0.5).len(), 0);
+    }
+
+    #[test]
+    fn test_arange_inclusive() {
+        let it = arange(0.0..=2.0, (0.5, Tolerance::Ulps(0)));
+        check_double_ended_iter(it, [0.0, 0.5, 1.0, 1.5, 2.0]);
+
+        let it = arange(0.0..=2.2, (0.5, Tolerance::Ulps(4)));
+        check_double_ended_iter(it, [0.0, 0.5, 1.0, 1.5, 2.0]);
+
+        let it = arange(2.0..=0.0, (-0.5, Tolerance::Ulps(0)));
+        check_double_ended_iter(it, [2.0, 1.5, 1.0, 0.5, 0.0]);
+
+        let it = arange(1.0..=1.0, (0.5, Tolerance::Ulps(0)));
+        check_double_ended_iter(it, [1.0]);
+
+        assert_eq!(arange(0.0..=2.0, (-0.5, Tolerance::Ulps(4))).len(), 0);
+    }
+
+    #[test]
+    fn test_arange_inclusive_exact_end() {
+        for (end, len) in [
+            (0.3, 4),
+            (0.7, 8),
+            (1.0, 11),
+            (2.0, 21),
+            (2.3, 24),
+            (10.0, 101),
+        ] {
+            let it = arange(0.0..=end, (0.1, Tolerance::Ulps(4)));
+            assert_eq!(it.len(), len);
+            assert_eq!(it.last(), Some(end));
+
+            let it = arange(0.0f32..=end as f32, (0.1, Tolerance::Ulps(4)));
+            assert_eq!(it.len(), len);
+            assert_eq!(it.last(), Some(end as f32));
+        }
+    }
+
+    #[test]
+    fn test_arange_inclusive_relative() {
+        // only the last value is replaced by `end`, the rest still step from `start`
+        let it = arange(0.0..=2.04, (0.5, Tolerance::Relative(0.1)));
+        check_double_ended_iter(it, [0.0, 0.5, 1.0, 1.5, 2.04]);
+
+        let it = arange(0.0..=2.06, (0.5, Tolerance::Relative(0.1)));
+        check_double_ended_iter(it, [0.0, 0.5, 1.0, 1.5, 2.0]);
+
+        let it = arange(0.0..=1.96, (0.5, Tolerance::Relative(0.1)));
+        check_double_ended_iter(it, [0.0, 0.5, 1.0, 1.5, 1.96]);
+
+        let it = arange(0.0..=1.94, (0.5, Tolerance::Relative(0.1)));
+        check_double_ended_iter(it, [0.0, 0.5, 1.0, 1.5]);
+
+        let it = arange(0.0..=1.0, (0.25, Tolerance::Relative(0.5)));
+        check_double_ended_iter(it, [0.0, 0.25, 0.5, 0.75, 1.0]);
+        let it = arange(0.0..=1.1, (0.25, Tolerance::Relative(0.5)));
+        check_double_ended_iter(it, [0.0, 0.25, 0.5, 0.75, 1.1]);
+
+        let mut buf = [0.0; 5];
+        let n = arange(0.0..=2.04, (0.5, Tolerance::Relative(0.1))).fill_into(&mut buf);
+        assert_eq!(buf[..n], [0.0, 0.5, 1.0, 1.5, 2.04]);
+    }
+
+    #[test]
+    fn test_try_arange_inclusive() {
+        let it = try_arange(0.0..=2.0, (0.5, Tolerance::Ulps(0))).unwrap();
+        assert!(it.eq(vec![0.0, 0.5, 1.0, 1.5, 2.0]));
+
+        let err = try_arange(0.0..=2.0, (-0.5, Tolerance::Ulps(0))).unwrap_err();
+        assert_eq!(err, SpaceError::WrongSignStep);
+        let err = try_arange(0.0..=2.0, (0.0, Tolerance::Ulps(0))).unwrap_err();
+        assert_eq!(err, SpaceError::ZeroStep);
+        let err = try_arange(0.0..=f64::NAN, (0.5, Tolerance::Ulps(0))).unwrap_err();
+        assert_eq!(err, SpaceError::NonFiniteBound);
+    }
+
+    #[test]
+    fn test_arange_fill_into() {
+        let mut it = arange(0.0..1.0, 0.1);
+        let expected: Vec<f64> = it.clone().collect();
+        let mut buf = [0.0; 4];
+        let mut values = vec![];
+        loop {
+            let n = it.fill_into(&mut buf);
+            if n == 0 {
+                break;
+            }
+            values.extend_from_slice(&buf[..n]);
+        }
+        assert_eq!(values, expected);
+
+        let mut it = arange(1.0..=2.0, (0.25, Tolerance::Ulps(4)));
+        let mut buf = [0.0; 5];
+        assert_eq!(it.fill_into(&mut buf), 5);
+        assert_eq!(buf, [1.0, 1.25, 1.5, 1.75, 2.0]);
+    }
+
+    #[test]
+    fn test_try_arange() {
+        let it = try_arange(0.0..2.0, 0.5).unwrap();
+        assert!(it.eq(vec![0.0, 0.5, 1.0, 1.5]));
+
+        let it = try_arange(1.0..1.0, 0.5).unwrap();
+        assert_eq!(it.len(), 0);
+
+        let it = try_arange(2.0..0.0, -0.5).unwrap();
+        assert!(it.eq(vec![2.0, 1.5, 1.0, 0.5]));
+    }
+
+    #[test]
+    fn test_try_arange_errors() {
+        assert_eq!(try_arange(0.0..2.0, 0.0).unwrap_err(), SpaceError::ZeroStep);
+        assert_eq!(
+            try_arange(0.0..2.0, -0.5).unwrap_err(),
+            SpaceError::WrongSignStep
+        );
+        assert_eq!(
+            try_arange(2.0..0.0, 0.5).unwrap_err(),
+            SpaceError::WrongSignStep
+        );
+        assert_eq!(
+            try_arange(0.0..f64::NAN, 0.5).unwrap_err(),
+            SpaceError::NonFiniteBound
+        );
+        assert_eq!(
+            try_arange(0.0..1.0, f64::INFINITY).unwrap_err(),
+            SpaceError::NonFiniteBound
+        );
+        assert_eq!(
+            try_arange(0.0..1.0, f64::MIN_POSITIVE).unwrap_err(),
+            SpaceError::CountOverflow
+        );
+    }
 }
@@ -74,7 +74,7 @@ where
             space
         });
 
-        IntoArangeGrid::new(len, GridSpaceInterpolation(lerps))
+        IntoArangeGrid::new(len, GridSpaceInterpolation::new(lerps))
     }
 }
 impl<F: Copy, const N: usize> ToArangeGrid<F, N> for Range<[F; N]>
@@ -93,7 +93,7 @@ where
             space
         });
 
-        IntoArangeGrid::new(len, GridSpaceInterpolation(lerps))
+        IntoArangeGrid::new(len, GridSpaceInterpolation::new(lerps))
     }
 }
 
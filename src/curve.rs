@@ -0,0 +1,341 @@
+use core::iter::FusedIterator;
+
+use crate::{
+    odometer::GridInterpolation,
+    space::{Interpolate, Space},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Curve {
+    Morton,
+    Hilbert,
+}
+
+/// [`Iterator`] over the points of a grid along a space-filling curve,
+/// created by [`Space::morton`] or [`Space::hilbert`].
+///
+/// The curve covers the smallest power of two sized cube around the grid,
+/// and the parts of the curve outside of the grid are skipped a whole sub-cube at a time.
+/// This does not support random access, since that would need to count the skipped points.
+#[derive(Clone, Debug)]
+pub struct GridCurve<I, const N: usize> {
+    interpolate: I,
+    curve: Curve,
+    lens: [usize; N],
+    /// The number of bits in the index along each axis of the curve
+    bits: u32,
+    /// The position along the curve of the next point from the front
+    front: u128,
+    /// The position along the curve after the next point from the back
+    back: u128,
+    /// How many points are left between `front` and `back`
+    remaining: usize,
+}
+
+impl<I> Space<I> {
+    /// Visit the points of a grid along a Morton (Z-order) curve, which keeps nearby points close together.
+    /// The bits of the index along each axis are interleaved, with the first axis the least significant.
+    /// This replaces the [`GridOrder`](crate::GridOrder) and serpentine order
+    ///
+    /// # Panics
+    ///
+    /// If values have already been taken from the iterator, or if the curve would need more than 127 bits to index
+    ///
+    /// ```
+    /// use iter_num_tools::{grid_space, grid_step};
+    ///
+    /// let it = grid_space([0.0, 0.0]..=[2.0, 1.0], [3, 2]).morton();
+    /// assert!(it.eq([
+    ///     [0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [1.0, 1.0],
+    ///     [2.0, 0.0], [2.0, 1.0],
+    /// ]));
+    ///
+    /// let it = grid_step([0, 0]..[4, 2]).morton();
+    /// assert!(it.eq([[0, 0], [1, 0], [0, 1], [1, 1], [2, 0], [3, 0], [2, 1], [3, 1]]));
+    /// ```
+    pub fn morton<const N: usize>(self) -> GridCurve<I, N>
+    where
+        I: GridInterpolation<N>,
+    {
+        self.assert_unstarted("Morton order");
+        GridCurve::new(self.interpolate, Curve::Morton)
+    }
+
+    /// Visit the points of a grid along a Hilbert curve, where each point
+    /// is a single step along one axis from the last when the grid is a power of two long.
+    /// This replaces the [`GridOrder`](crate::GridOrder) and serpentine order
+    ///
+    /// # Panics
+    ///
+    /// If values have already been taken from the iterator, or if the curve would need more than 127 bits to index
+    ///
+    /// ```
+    /// use iter_num_tools::grid_space;
+    ///
+    /// let it = grid_space([0.0, 0.0]..=[1.0, 1.0], 2).hilbert();
+    /// assert!(it.eq([[0.0, 0.0], [0.0, 1.0], [1.0, 1.0], [1.0, 0.0]]));
+    /// ```
+    pub fn hilbert<const N: usize>(self) -> GridCurve<I, N>
+    where
+        I: GridInterpolation<N>,
+    {
+        self.assert_unstarted("Hilbert order");
+        GridCurve::new(self.interpolate, Curve::Hilbert)
+    }
+}
+
+impl<I: GridInterpolation<N>, const N: usize> GridCurve<I, N> {
+    fn new(interpolate: I, curve: Curve) -> Self {
+        let lens = interpolate.lens();
+        let remaining = lens.iter().product();
+        let max = lens.iter().copied().max().unwrap_or(0);
+        let bits = max.next_power_of_two().trailing_zeros();
+        assert!(
+            (bits as usize) * N < 128,
+            "grid is too large to index along a space-filling curve"
+        );
+        let back = match remaining {
+            0 => 0,
+            _ => 1 << (bits as usize * N),
+        };
+        GridCurve {
+            interpolate: interpolate.unordered(),
+            curve,
+            lens,
+            bits,
+            front: 0,
+            back,
+            remaining,
+        }
+    }
+}
+
+impl<I, const N: usize> GridCurve<I, N> {
+    /// The index along each axis of the point at position `h` along the curve
+    fn axes(&self, h: u128) -> [usize; N] {
+        match self.curve {
+            Curve::Morton => morton_axes(h, self.bits),
+            Curve::Hilbert => hilbert_axes(h, self.bits),
+        }
+    }
+
+    /// The flat index of a point, if it is inside the grid
+    fn flat_index(&self, index: [usize; N]) -> Option<usize> {
+        let mut x = 0;
+        let mut stride = 1;
+        for (z, len) in index.into_iter().zip(self.lens) {
+            if z >= len {
+                return None;
+            }
+            x += z * stride;
+            stride *= len;
+        }
+        Some(x)
+    }
+
+    /// The largest level `l <= align` where the `2^(N * l)` positions of the curve around `index`
+    /// are all outside of the grid, or `None` if `index` is inside it.
+    ///
+    /// Both curves cover an aligned cube of side `2^l` in each aligned block of `2^(N * l)` positions,
+    /// so the whole block can be skipped when the corner of that cube is already past the end of an axis
+    fn outside_level(&self, index: [usize; N], align: u32) -> Option<u32> {
+        (0..=align).rev().find(|&l| {
+            index
+                .into_iter()
+                .zip(self.lens)
+                .any(|(z, len)| z >> l << l >= len)
+        })
+    }
+
+    /// How many levels of the curve the position `h` is aligned to
+    fn alignment(&self, h: u128) -> u32 {
+        (h.trailing_zeros() / N.max(1) as u32).min(self.bits)
+    }
+}
+
+/// The index along each axis of position `h` along a Morton (Z-order) curve,
+/// with the bits of each axis interleaved so that the first axis varies fastest
+fn morton_axes<const N: usize>(h: u128, bits: u32) -> [usize; N] {
+    let mut axes = [0; N];
+    for bit in 0..bits {
+        for (i, z) in axes.iter_mut().enumerate() {
+            *z |= (((h >> (bit as usize * N + i)) & 1) as usize) << bit;
+        }
+    }
+    axes
+}
+
+/// The index along each axis of position `h` along a Hilbert curve.
+///
+/// Uses the method from J. Skilling, "Programming the Hilbert curve" (2004),
+/// undoing the rotations and reflections from the coarsest level down
+fn hilbert_axes<const N: usize>(h: u128, bits: u32) -> [usize; N] {
+    // transpose, so each axis takes every Nth bit of the position, with the first axis most significant
+    let mut x = [0usize; N];
+    for bit in 0..bits {
+        for (i, z) in x.iter_mut().enumerate() {
+            *z |= (((h >> (bit as usize * N + N - 1 - i)) & 1) as usize) << bit;
+        }
+    }
+    if N == 0 {
+        return x;
+    }
+
+    // gray decode
+    let t = x[N - 1] >> 1;
+    for i in (1..N).rev() {
+        x[i] ^= x[i - 1];
+    }
+    x[0] ^= t;
+
+    // undo the excess work
+    let mut q = 2;
+    while q < 1 << bits {
+        let p = q - 1;
+        for i in (0..N).rev() {
+            if x[i] & q != 0 {
+                x[0] ^= p;
+            } else {
+                let t = (x[0] ^ x[i]) & p;
+                x[0] ^= t;
+                x[i] ^= t;
+            }
+        }
+        q <<= 1;
+    }
+    x
+}
+
+impl<I: Interpolate + Copy, const N: usize> Iterator for GridCurve<I, N> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.remaining > 0 {
+            let h = self.front;
+            let index = self.axes(h);
+            match self.outside_level(index, self.alignment(h)) {
+                Some(l) => self.front += 1 << (l as usize * N),
+                None => {
+                    self.front += 1;
+                    self.remaining -= 1;
+                    let x = self.flat_index(index)?;
+                    return Some(self.interpolate.interpolate(x));
+                }
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<I: Interpolate + Copy, const N: usize> DoubleEndedIterator for GridCurve<I, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        while self.remaining > 0 {
+            let h = self.back - 1;
+            let index = self.axes(h);
+            match self.outside_level(index, self.alignment(self.back)) {
+                Some(l) => self.back -= 1 << (l as usize * N),
+                None => {
+                    self.back -= 1;
+                    self.remaining -= 1;
+                    let x = self.flat_index(index)?;
+                    return Some(self.interpolate.interpolate(x));
+                }
+            }
+        }
+        None
+    }
+}
+
+impl<I: Interpolate + Copy, const N: usize> ExactSizeIterator for GridCurve<I, N> {
+    fn len(&self) -> usize {
+        self.remaining
+    }
+}
+
+impl<I: Interpolate + Copy, const N: usize> FusedIterator for GridCurve<I, N> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{check_meet_in_middle, odometer::GridIndex};
+
+    #[test]
+    fn test_morton_axes() {
+        let points: [[usize; 2]; 8] = core::array::from_fn(|h| morton_axes(h as u128, 2));
+        assert_eq!(
+            points,
+            [
+                [0, 0],
+                [1, 0],
+                [0, 1],
+                [1, 1],
+                [2, 0],
+                [3, 0],
+                [2, 1],
+                [3, 1]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_curve_skips_outside() {
+        for curve in [Curve::Morton, Curve::Hilbert] {
+            for lens in [[30, 1, 1], [1, 2, 30], [3, 5, 7], [1, 1, 1], [8, 8, 8]] {
+                let it = GridCurve::new(GridIndex::space(lens).interpolate, curve);
+
+                // the same points as scanning every position of the cube
+                let expected: Vec<_> = (0..it.back)
+                    .map(|h| it.axes(h))
+                    .filter(|index| it.flat_index(*index).is_some())
+                    .collect();
+                assert_eq!(expected.len(), lens.iter().product());
+                check_meet_in_middle(it, &expected);
+            }
+        }
+
+        // far too many positions to scan one at a time
+        let it = GridIndex::space([600, 1, 1]).morton();
+        assert!(it.eq((0..600).map(|z| [z, 0, 0])));
+        let it = GridIndex::space([100_000, 2]).hilbert();
+        assert_eq!(it.clone().count(), 200_000);
+        assert_eq!(it.rev().count(), 200_000);
+        let it = GridIndex::space([1 << 16, 1, 1, 1]).morton();
+        assert!(it.eq((0..1 << 16).map(|z| [z, 0, 0, 0])));
+    }
+
+    #[test]
+    #[should_panic = "Morton order must be set before any values are taken"]
+    fn test_curve_after_next() {
+        let mut it = GridIndex::space([2, 2]);
+        it.next();
+        let _ = it.morton();
+    }
+
+    #[test]
+    fn test_hilbert_axes() {
+        let points: [[usize; 2]; 4] = core::array::from_fn(|h| hilbert_axes(h as u128, 1));
+        assert_eq!(points, [[0, 0], [0, 1], [1, 1], [1, 0]]);
+
+        // every point of the cube is visited once, each a single step from the last
+        for bits in 1..4 {
+            let side = 1usize << bits;
+            let mut seen = vec![false; side.pow(3)];
+            let mut last: Option<[usize; 3]> = None;
+            for h in 0..side.pow(3) {
+                let p: [usize; 3] = hilbert_axes(h as u128, bits);
+                let i = p[0] + side * (p[1] + side * p[2]);
+                assert!(!seen[i]);
+                seen[i] = true;
+                if let Some(last) = last {
+                    let moved: usize = (0..3).map(|k| p[k].abs_diff(last[k])).sum();
+                    assert_eq!(moved, 1, "{last:?} {p:?}");
+                }
+                last = Some(p);
+            }
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use core::fmt;
+use num_traits::Num;
+
+/// Error returned by the fallible space constructors, such as [`try_lin_space`](crate::try_lin_space)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SpaceError {
+    /// A space was requested with zero steps
+    ZeroSteps,
+    /// The bounds of the range (or the distance between them, or the ratio of a geometric step) are NaN or infinite
+    NonFiniteBound,
+    /// A step of zero would never reach the end of the range
+    ZeroStep,
+    /// The step moves away from the end of the range
+    WrongSignStep,
+    /// A logarithmic space was requested over a range that is not strictly positive
+    NonPositiveLogBound,
+    /// The number of values does not fit in the types involved
+    CountOverflow,
+    /// A geometric space was requested with a bound of zero
+    ZeroBound,
+    /// A geometric space was requested between a positive and a negative bound
+    MixedSignBounds,
+    /// A geometric step was requested with a ratio that is not positive
+    NonPositiveRatio,
+    /// A geometric step was requested with a ratio of one, which would never reach the end of the range
+    UnitRatio,
+    /// A logarithmic space was requested with a base that is not positive, not finite, or one
+    InvalidBase,
+}
+
+impl fmt::Display for SpaceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            SpaceError::ZeroSteps => "space must have at least one step",
+            SpaceError::NonFiniteBound => "space bounds must be finite",
+            SpaceError::ZeroStep => "step size must not be zero",
+            SpaceError::WrongSignStep => "step moves away from the end of the range",
+            SpaceError::NonPositiveLogBound => "logarithmic space bounds must be positive",
+            SpaceError::CountOverflow => "number of values in the space overflowed",
+            SpaceError::ZeroBound => "geometric space bounds must not be zero",
+            SpaceError::MixedSignBounds => "geometric space bounds must have the same sign",
+            SpaceError::NonPositiveRatio => "geometric step ratio must be positive",
+            SpaceError::UnitRatio => "geometric step ratio must not be one",
+            SpaceError::InvalidBase => "logarithm base must be positive, finite and not one",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SpaceError {}
+
+/// Checks that the value is neither infinite nor NaN.
+/// Integers are always finite, while `inf - inf` and `NaN - NaN` are both NaN.
+#[inline]
+#[allow(clippy::eq_op)]
+pub(crate) fn is_finite<T: Num + Copy>(x: T) -> bool {
+    x - x == T::zero()
+}
@@ -0,0 +1,211 @@
+use core::ops::{Range, RangeInclusive};
+use num_traits::{real::Real, FromPrimitive};
+
+use crate::{
+    error::{is_finite, SpaceError},
+    logspace::{IntoLogSpace, LogSpace, LogarithmicInterpolation},
+};
+
+/// [`Iterator`] returned by [`geom_space`]
+pub type GeomSpace<T> = LogSpace<T>;
+
+/// [`IntoIterator`] returned by [`ToGeomSpace::into_geom_space`]
+pub type IntoGeomSpace<T> = IntoLogSpace<T>;
+
+/// Creates a geometric space over range with a fixed number of steps.
+///
+/// This is like [`log_space`](crate::log_space), but the range may also be entirely negative.
+/// The first and last values are exactly the bounds of the range.
+///
+/// # Panics
+///
+/// If either bound is zero, or the bounds have different signs.
+/// See [`try_geom_space`] for a non-panicking version.
+///
+/// ```
+/// use iter_num_tools::geom_space;
+/// use itertools::zip_eq;
+///
+/// let it = geom_space(1.0..=1000.0, 4);
+/// let expected: Vec<f64> = vec![1.0, 10.0, 100.0, 1000.0];
+/// assert!(zip_eq(it, expected).all(|(x, y)| (x-y).abs() < 1e-10));
+///
+/// // Negative ranges
+/// let it = geom_space(-1000.0..=-1.0, 4);
+/// let expected: Vec<f64> = vec![-1000.0, -100.0, -10.0, -1.0];
+/// assert!(zip_eq(it, expected).all(|(x, y)| (x-y).abs() < 1e-10));
+/// ```
+pub fn geom_space<R>(range: R, steps: usize) -> GeomSpace<R::Item>
+where
+    R: ToGeomSpace,
+{
+    range.into_geom_space(steps).into_space()
+}
+
+/// Creates a geometric space over range with a fixed number of steps,
+/// returning an error instead of panicking if the space is invalid
+///
+/// ```
+/// use iter_num_tools::{try_geom_space, SpaceError};
+///
+/// let it = try_geom_space(-1.0..=-100.0, 3).unwrap();
+/// assert_eq!(it.len(), 3);
+///
+/// assert_eq!(try_geom_space(-1.0..=100.0, 3).unwrap_err(), SpaceError::MixedSignBounds);
+/// assert_eq!(try_geom_space(0.0..=100.0, 3).unwrap_err(), SpaceError::ZeroBound);
+/// ```
+pub fn try_geom_space<R>(range: R, steps: usize) -> Result<GeomSpace<R::Item>, SpaceError>
+where
+    R: ToGeomSpace,
+{
+    range
+        .try_into_geom_space(steps)
+        .map(IntoGeomSpace::into_space)
+}
+
+/// A helper trait for [`geom_space`]
+pub trait ToGeomSpace {
+    /// The item that this is a geometric space over
+    type Item;
+    /// Create the geometric space
+    fn into_geom_space(self, steps: usize) -> IntoGeomSpace<Self::Item>;
+    /// Create the geometric space, checking that the range and number of steps are valid
+    fn try_into_geom_space(self, steps: usize) -> Result<IntoGeomSpace<Self::Item>, SpaceError>;
+}
+
+pub(crate) fn check_geom_bounds<T: Real>(start: T, end: T) -> Result<(), SpaceError> {
+    if !is_finite(start) || !is_finite(end) {
+        Err(SpaceError::NonFiniteBound)
+    } else if start.is_zero() || end.is_zero() {
+        Err(SpaceError::ZeroBound)
+    } else if start.is_sign_negative() != end.is_sign_negative() {
+        Err(SpaceError::MixedSignBounds)
+    } else {
+        Ok(())
+    }
+}
+
+/// Steps from `start` to `end` in `end_index` multiplicative steps.
+/// Works on the magnitudes, so that negative ranges share the same ratio
+fn geometric<T: Real + FromPrimitive>(
+    start: T,
+    end: T,
+    end_index: usize,
+) -> LogarithmicInterpolation<T> {
+    let ln_step = match end_index {
+        0 => T::zero(),
+        _ => (end.abs().ln() - start.abs().ln()) / T::from_usize(end_index).unwrap(),
+    };
+    LogarithmicInterpolation::between(start, end, ln_step, end_index)
+}
+
+impl<T: Real + FromPrimitive> ToGeomSpace for Range<T> {
+    type Item = T;
+
+    fn into_geom_space(self, steps: usize) -> IntoGeomSpace<Self::Item> {
+        let Range { start, end } = self;
+        if let Err(err) = check_geom_bounds(start, end) {
+            panic!("invalid geometric space: {err}");
+        }
+        IntoGeomSpace::new(steps, geometric(start, end, steps))
+    }
+
+    fn try_into_geom_space(self, steps: usize) -> Result<IntoGeomSpace<Self::Item>, SpaceError> {
+        let Range { start, end } = self;
+        if steps == 0 {
+            return Err(SpaceError::ZeroSteps);
+        }
+        check_geom_bounds(start, end)?;
+        T::from_usize(steps).ok_or(SpaceError::CountOverflow)?;
+        Ok(IntoGeomSpace::new(steps, geometric(start, end, steps)))
+    }
+}
+
+impl<T: Real + FromPrimitive> ToGeomSpace for RangeInclusive<T> {
+    type Item = T;
+
+    fn into_geom_space(self, steps: usize) -> IntoGeomSpace<Self::Item> {
+        let (start, end) = self.into_inner();
+        if let Err(err) = check_geom_bounds(start, end) {
+            panic!("invalid geometric space: {err}");
+        }
+        IntoGeomSpace::new(steps, geometric(start, end, steps.saturating_sub(1)))
+    }
+
+    fn try_into_geom_space(self, steps: usize) -> Result<IntoGeomSpace<Self::Item>, SpaceError> {
+        let (start, end) = self.into_inner();
+        if steps == 0 {
+            return Err(SpaceError::ZeroSteps);
+        }
+        check_geom_bounds(start, end)?;
+        T::from_usize(steps - 1).ok_or(SpaceError::CountOverflow)?;
+        Ok(IntoGeomSpace::new(steps, geometric(start, end, steps - 1)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use itertools::zip_eq;
+
+    fn approx_eq<const N: usize>(it: impl Iterator<Item = f64>, expected: [f64; N]) -> bool {
+        zip_eq(it, expected).all(|(a, b)| ((a - b) / b).abs() < 1e-12)
+    }
+
+    #[test]
+    fn test_geom_space_inclusive() {
+        let it = geom_space(1.0..=1000.0, 4);
+        assert!(approx_eq(it.clone(), [1.0, 10.0, 100.0, 1000.0]));
+        assert!(approx_eq(it.rev(), [1000.0, 100.0, 10.0, 1.0]));
+    }
+
+    #[test]
+    fn test_geom_space_exclusive() {
+        let it = geom_space(1.0..1000.0, 3);
+        assert!(approx_eq(it.clone(), [1.0, 10.0, 100.0]));
+        assert!(approx_eq(it.rev(), [100.0, 10.0, 1.0]));
+    }
+
+    #[test]
+    fn test_geom_space_negative() {
+        let it = geom_space(-1.0..=-1000.0, 4);
+        assert!(approx_eq(it, [-1.0, -10.0, -100.0, -1000.0]));
+
+        let it = geom_space(-1000.0..-1.0, 3);
+        assert!(approx_eq(it, [-1000.0, -100.0, -10.0]));
+    }
+
+    #[test]
+    fn test_geom_space_exact_endpoints() {
+        for (start, end) in [(0.3, 7.0), (-0.3, -7.0), (-2e5, -3e-4), (5.0, 0.002)] {
+            for steps in [2, 3, 10, 1001] {
+                let it = geom_space(start..=end, steps);
+                assert_eq!(it.len(), steps);
+                assert_eq!(it.clone().next(), Some(start));
+                assert_eq!(it.last(), Some(end));
+            }
+        }
+        assert!(geom_space(-3.0..=-7.0, 1).eq([-3.0]));
+    }
+
+    #[test]
+    fn test_try_geom_space_errors() {
+        let err = |r: RangeInclusive<f64>| try_geom_space(r, 3).unwrap_err();
+        assert_eq!(err(-1.0..=1.0), SpaceError::MixedSignBounds);
+        assert_eq!(err(1.0..=-1.0), SpaceError::MixedSignBounds);
+        assert_eq!(err(0.0..=1.0), SpaceError::ZeroBound);
+        assert_eq!(err(-1.0..=-0.0), SpaceError::ZeroBound);
+        assert_eq!(err(1.0..=f64::INFINITY), SpaceError::NonFiniteBound);
+        assert_eq!(
+            try_geom_space(1.0..2.0, 0).unwrap_err(),
+            SpaceError::ZeroSteps
+        );
+    }
+
+    #[test]
+    #[should_panic = "invalid geometric space"]
+    fn test_geom_space_mixed_sign_panics() {
+        geom_space(-1.0..=1.0, 3);
+    }
+}
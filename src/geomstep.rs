@@ -0,0 +1,249 @@
+use core::ops::Range;
+use num_traits::{real::Real, FromPrimitive};
+
+use crate::{
+    error::{is_finite, SpaceError},
+    geomspace::check_geom_bounds,
+    space::{Interpolate, IntoSpace, Space},
+};
+
+/// Create a new iterator over the range, multiplying by `ratio` each time.
+/// This is the fixed ratio counterpart to [`geom_space`](crate::geom_space),
+/// in the same way that [`arange`](crate::arange) is to [`lin_space`](crate::lin_space)
+///
+/// # Panics
+///
+/// If either bound is zero, the bounds have different signs,
+/// or the ratio is not finite, not positive, or is one.
+/// See [`try_geom_step`] for a non-panicking version.
+///
+/// ```
+/// use iter_num_tools::geom_step;
+///
+/// let it = geom_step(1.0..1000.0, 2.0);
+/// assert!(it.eq(vec![1.0, 2.0, 4.0, 8.0, 16.0, 32.0, 64.0, 128.0, 256.0, 512.0]));
+///
+/// // ratios below 1 shrink the magnitude towards zero
+/// let it = geom_step(8.0..1.0, 0.5);
+/// assert!(it.eq(vec![8.0, 4.0, 2.0]));
+/// let it = geom_step(-8.0..-1.0, 0.5);
+/// assert!(it.eq(vec![-8.0, -4.0, -2.0]));
+/// ```
+pub fn geom_step<R, F>(range: R, ratio: F) -> GeomStep<R::Item>
+where
+    R: ToGeomStep<F>,
+{
+    range.into_geom_step(ratio).into_space()
+}
+
+/// Create a new iterator over the range, multiplying by `ratio` each time,
+/// returning an error instead of panicking if the space is invalid
+///
+/// ```
+/// use iter_num_tools::{try_geom_step, SpaceError};
+///
+/// let it = try_geom_step(1.0..10.0, 3.0).unwrap();
+/// assert!(it.eq(vec![1.0, 3.0, 9.0]));
+///
+/// assert_eq!(try_geom_step(1.0..10.0, 0.5).unwrap_err(), SpaceError::WrongSignStep);
+/// assert_eq!(try_geom_step(1.0..10.0, -2.0).unwrap_err(), SpaceError::NonPositiveRatio);
+/// ```
+pub fn try_geom_step<R, F>(range: R, ratio: F) -> Result<GeomStep<R::Item>, SpaceError>
+where
+    R: ToGeomStep<F>,
+{
+    range
+        .try_into_geom_step(ratio)
+        .map(IntoGeomStep::into_space)
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct GeometricInterpolation<T> {
+    pub start: T,
+    pub ratio: T,
+}
+
+impl<T: Real + FromPrimitive> Interpolate for GeometricInterpolation<T> {
+    type Item = T;
+    fn interpolate(self, x: usize) -> T {
+        // `powf` of the given ratio is exact for exact powers, unlike `exp` of its log
+        self.start * self.ratio.powf(T::from_usize(x).unwrap())
+    }
+}
+
+/// Helper trait for [`geom_step`]
+pub trait ToGeomStep<S> {
+    /// The item that this is a geometric step space over
+    type Item;
+    /// Create the geometric step space
+    fn into_geom_step(self, ratio: S) -> IntoGeomStep<Self::Item>;
+    /// Create the geometric step space, checking that the range and ratio are valid
+    fn try_into_geom_step(self, ratio: S) -> Result<IntoGeomStep<Self::Item>, SpaceError>;
+}
+
+/// How far from a power of the ratio the end can be
+/// while still being treated as landing on it, and excluded
+const END_ULPS: u32 = 4;
+
+/// Counts the values in a geometric step space, the same way as
+/// [`arange`](crate::arange) counts them but using logarithms
+fn geom_step_len<F: Real>(start: F, end: F, ratio: F) -> F {
+    let steps = (end.abs().ln() - start.abs().ln()) / ratio.ln();
+    let nearest = steps.round();
+    let diff = (end - start * ratio.powf(nearest)).abs();
+    if diff <= F::epsilon() * end.abs() * F::from(END_ULPS).unwrap() {
+        nearest
+    } else {
+        steps.ceil()
+    }
+}
+
+impl<F: Real + FromPrimitive> ToGeomStep<F> for Range<F> {
+    type Item = F;
+
+    fn into_geom_step(self, ratio: F) -> IntoGeomStep<Self::Item> {
+        let Range { start, end } = self;
+        if let Err(err) = check_geom_bounds(start, end) {
+            panic!("invalid geometric space: {err}");
+        }
+        assert!(is_finite(ratio), "{}", SpaceError::NonFiniteBound);
+        assert!(ratio > F::zero(), "{}", SpaceError::NonPositiveRatio);
+        assert!(!ratio.is_one(), "{}", SpaceError::UnitRatio);
+
+        // a ratio that moves away from the end never reaches it, so the space is empty
+        let len = geom_step_len(start, end, ratio).max(F::zero());
+
+        IntoGeomStep::new(
+            len.to_usize().unwrap(),
+            GeometricInterpolation { start, ratio },
+        )
+    }
+
+    fn try_into_geom_step(self, ratio: F) -> Result<IntoGeomStep<Self::Item>, SpaceError> {
+        let Range { start, end } = self;
+        check_geom_bounds(start, end)?;
+        if !is_finite(ratio) {
+            return Err(SpaceError::NonFiniteBound);
+        }
+        if ratio <= F::zero() {
+            return Err(SpaceError::NonPositiveRatio);
+        }
+        if ratio.is_one() {
+            return Err(SpaceError::UnitRatio);
+        }
+
+        let len = geom_step_len(start, end, ratio);
+        if len.is_sign_negative() && !len.is_zero() {
+            return Err(SpaceError::WrongSignStep);
+        }
+
+        Ok(IntoGeomStep::new(
+            len.to_usize().ok_or(SpaceError::CountOverflow)?,
+            GeometricInterpolation { start, ratio },
+        ))
+    }
+}
+
+/// [`Iterator`] returned by [`geom_step`]
+pub type GeomStep<T> = Space<GeometricInterpolation<T>>;
+
+/// [`IntoIterator`] returned by [`ToGeomStep::into_geom_step`]
+pub type IntoGeomStep<T> = IntoSpace<GeometricInterpolation<T>>;
+
+impl<T: Copy> GeomStep<T> {
+    /// The first value of the space, before any values were taken from the iterator
+    ///
+    /// ```
+    /// use iter_num_tools::geom_step;
+    ///
+    /// let it = geom_step(3.0..100.0, 2.0);
+    /// assert_eq!((it.start(), it.ratio()), (3.0, 2.0));
+    /// ```
+    pub fn start(&self) -> T {
+        self.interpolate.start
+    }
+
+    /// The ratio between consecutive values
+    pub fn ratio(&self) -> T {
+        self.interpolate.ratio
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::check_double_ended_iter;
+
+    use super::*;
+
+    #[test]
+    fn test_geom_step() {
+        check_double_ended_iter(
+            geom_step(1.0..1000.0, 2.0),
+            [1.0, 2.0, 4.0, 8.0, 16.0, 32.0, 64.0, 128.0, 256.0, 512.0],
+        );
+        check_double_ended_iter(geom_step(3.0..100.0, 3.0), [3.0, 9.0, 27.0, 81.0]);
+    }
+
+    #[test]
+    fn test_geom_step_descending() {
+        check_double_ended_iter(geom_step(1000.0..100.0, 0.5), [1000.0, 500.0, 250.0, 125.0]);
+        check_double_ended_iter(geom_step(-1.0..-100.0, 4.0), [-1.0, -4.0, -16.0, -64.0]);
+    }
+
+    #[test]
+    fn test_geom_step_excludes_end() {
+        // the end is never yielded, even if it is only approximately a power of the ratio
+        assert_eq!(geom_step(1.0..1024.0, 2.0).len(), 10);
+        assert_eq!(geom_step(1.0..1000.0, 10.0).len(), 3);
+        assert_eq!(geom_step(1.0..1e-3, 0.1).len(), 3);
+        assert_eq!(geom_step(1.0f32..1e6, 10.0).len(), 6);
+        for n in 1..300 {
+            assert_eq!(geom_step(1.0..1.1f64.powi(n), 1.1).len(), n as usize);
+        }
+    }
+
+    #[test]
+    fn test_geom_step_wrong_direction() {
+        assert_eq!(geom_step(1.0..1000.0, 0.5).len(), 0);
+        assert_eq!(geom_step(1000.0..1.0, 2.0).len(), 0);
+    }
+
+    #[test]
+    fn test_geom_step_extras() {
+        let mut it = geom_step(1.0..1000.0, 2.0);
+        assert_eq!(it.len(), 10);
+        assert_eq!(it.nth(2), Some(4.0));
+        assert_eq!(it.nth_back(2), Some(128.0));
+        assert_eq!(it.len(), 4);
+    }
+
+    #[test]
+    fn test_try_geom_step_errors() {
+        let err = |r: Range<f64>, ratio| try_geom_step(r, ratio).unwrap_err();
+        assert_eq!(err(1.0..10.0, 0.5), SpaceError::WrongSignStep);
+        assert_eq!(err(1.0..10.0, 1.0), SpaceError::UnitRatio);
+        assert_eq!(err(1.0..10.0, 0.0), SpaceError::NonPositiveRatio);
+        assert_eq!(err(1.0..10.0, f64::NAN), SpaceError::NonFiniteBound);
+        assert_eq!(err(1.0..10.0, f64::INFINITY), SpaceError::NonFiniteBound);
+        assert_eq!(err(0.0..10.0, 2.0), SpaceError::ZeroBound);
+        assert_eq!(err(-1.0..10.0, 2.0), SpaceError::MixedSignBounds);
+    }
+
+    #[test]
+    #[should_panic = "invalid geometric space"]
+    fn test_geom_step_mixed_sign_panics() {
+        geom_step(-1.0..10.0, 2.0);
+    }
+
+    #[test]
+    #[should_panic = "geometric step ratio must not be one"]
+    fn test_geom_step_ratio_one_panics() {
+        geom_step(1.0..10.0, 1.0);
+    }
+
+    #[test]
+    #[should_panic = "space bounds must be finite"]
+    fn test_geom_step_infinite_ratio_panics() {
+        geom_step(1.0..10.0, f64::INFINITY);
+    }
+}
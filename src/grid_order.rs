@@ -0,0 +1,142 @@
+use crate::{odometer::GridInterpolation, space::Space};
+
+/// The order that the points of a grid are visited in,
+/// set with [`GridSpace::with_order`](crate::GridSpace::with_order)
+///
+/// ```
+/// use iter_num_tools::{grid_step, GridOrder};
+///
+/// let it = grid_step([0, 0]..[2, 3]).with_order(GridOrder::RowMajor);
+/// assert!(it.eq([[0, 0], [0, 1], [0, 2], [1, 0], [1, 1], [1, 2]]));
+///
+/// let it = grid_step([0, 0, 0]..[2, 2, 2]).with_order(GridOrder::Axes([1, 2, 0]));
+/// assert!(it.eq([
+///     [0, 0, 0], [0, 1, 0],
+///     [0, 0, 1], [0, 1, 1],
+///
+///     [1, 0, 0], [1, 1, 0],
+///     [1, 0, 1], [1, 1, 1],
+/// ]));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum GridOrder<const N: usize> {
+    /// The first axis varies fastest, like Fortran arrays. This is the default
+    #[default]
+    ColumnMajor,
+    /// The last axis varies fastest, like C arrays
+    RowMajor,
+    /// The axes from the fastest varying to the slowest. Must contain each axis exactly once
+    Axes([usize; N]),
+}
+
+impl<const N: usize> GridOrder<N> {
+    /// The axes from the fastest varying to the slowest
+    ///
+    /// # Panics
+    ///
+    /// If the order is [`Axes`](GridOrder::Axes) but not a permutation of the axes
+    pub(crate) fn axes(self) -> [usize; N] {
+        match self {
+            GridOrder::ColumnMajor => core::array::from_fn(|i| i),
+            GridOrder::RowMajor => core::array::from_fn(|i| N - 1 - i),
+            GridOrder::Axes(axes) => {
+                let mut seen = [false; N];
+                for axis in axes {
+                    assert!(
+                        axis < N && !seen[axis],
+                        "grid order {axes:?} is not a permutation of the axes"
+                    );
+                    seen[axis] = true;
+                }
+                axes
+            }
+        }
+    }
+}
+
+impl<I> Space<I> {
+    /// Change the order that the points of a grid are visited in. The default is [`GridOrder::ColumnMajor`].
+    /// This must be set before any values are taken from the iterator
+    ///
+    /// # Panics
+    ///
+    /// If the order is [`GridOrder::Axes`] but not a permutation of the axes,
+    /// or if values have already been taken from the iterator
+    ///
+    /// ```
+    /// use iter_num_tools::{grid_space, GridOrder};
+    ///
+    /// let it = grid_space([0.0, 0.0]..=[1.0, 2.0], [2, 3]).with_order(GridOrder::RowMajor);
+    /// assert!(it.eq([
+    ///     [0.0, 0.0], [0.0, 1.0], [0.0, 2.0],
+    ///     [1.0, 0.0], [1.0, 1.0], [1.0, 2.0],
+    /// ]));
+    /// ```
+    pub fn with_order<const N: usize>(mut self, order: GridOrder<N>) -> Self
+    where
+        I: GridInterpolation<N>,
+    {
+        self.assert_unstarted("grid order");
+        let odometer = self.interpolate.odometer_mut();
+        *odometer = odometer.with_axes(order.axes());
+        self
+    }
+
+    /// Visit the points of a grid in serpentine (boustrophedon) order, where every other pass
+    /// along an axis goes backwards, so each point is a single step along one axis from the last.
+    /// This applies on top of the [`GridOrder`], and must be set before any values are taken from the iterator
+    ///
+    /// # Panics
+    ///
+    /// If values have already been taken from the iterator
+    ///
+    /// ```
+    /// use iter_num_tools::{grid_space, grid_step, GridOrder};
+    ///
+    /// let it = grid_space([0.0, 0.0]..=[1.0, 1.0], 3).serpentine();
+    /// assert!(it.eq([
+    ///     [0.0, 0.0], [0.5, 0.0], [1.0, 0.0],
+    ///     [1.0, 0.5], [0.5, 0.5], [0.0, 0.5],
+    ///     [0.0, 1.0], [0.5, 1.0], [1.0, 1.0],
+    /// ]));
+    ///
+    /// // combined with another order
+    /// let it = grid_step([0, 0]..[3, 2]).with_order(GridOrder::RowMajor).serpentine();
+    /// assert!(it.eq([[0, 0], [0, 1], [1, 1], [1, 0], [2, 0], [2, 1]]));
+    /// ```
+    pub fn serpentine<const N: usize>(mut self) -> Self
+    where
+        I: GridInterpolation<N>,
+    {
+        self.assert_unstarted("serpentine order");
+        let odometer = self.interpolate.odometer_mut();
+        *odometer = odometer.with_serpentine(true);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid_order_axes() {
+        assert_eq!(GridOrder::<3>::ColumnMajor.axes(), [0, 1, 2]);
+        assert_eq!(GridOrder::<3>::RowMajor.axes(), [2, 1, 0]);
+        assert_eq!(GridOrder::Axes([1, 2, 0]).axes(), [1, 2, 0]);
+        assert_eq!(GridOrder::<0>::RowMajor.axes(), []);
+    }
+
+    #[test]
+    #[should_panic = "not a permutation"]
+    fn test_grid_order_repeated_axis() {
+        GridOrder::Axes([1, 0, 1]).axes();
+    }
+
+    #[test]
+    #[should_panic = "not a permutation"]
+    fn test_grid_order_missing_axis() {
+        GridOrder::Axes([0, 3]).axes();
+    }
+}
@@ -1,10 +1,12 @@
 use array_bin_ops::Array;
 
 use crate::{
-    linspace::{LinearInterpolation, ToLinSpace},
+    linspace::{LinSpaceNum, LinearInterpolation, ToLinSpace},
+    odometer::{GridInterpolation, Odometer},
     space::{Interpolate, IntoSpace, Space},
 };
 use core::ops::{Range, RangeInclusive};
+use num_traits::real::Real;
 
 /// Creates a linear grid space over range with a fixed number of width and height steps
 ///
@@ -69,7 +71,7 @@ where
             lin_space
         });
 
-        IntoGridSpace::new(len, GridSpaceInterpolation(lerps))
+        IntoGridSpace::new(len, GridSpaceInterpolation::new(lerps))
     }
 }
 
@@ -90,7 +92,7 @@ where
             lin_space
         });
 
-        IntoGridSpace::new(len, GridSpaceInterpolation(lerps))
+        IntoGridSpace::new(len, GridSpaceInterpolation::new(lerps))
     }
 }
 
@@ -105,7 +107,7 @@ where
 
         let lerps = Array(start).zip_map(end, |start, end| (start..end).into_lin_space(steps));
 
-        IntoGridSpace::new(steps.pow(N as u32), GridSpaceInterpolation(lerps))
+        IntoGridSpace::new(steps.pow(N as u32), GridSpaceInterpolation::new(lerps))
     }
 }
 
@@ -120,24 +122,205 @@ where
 
         let lerps = Array(start).zip_map(end, |start, end| (start..=end).into_lin_space(steps));
 
-        IntoGridSpace::new(steps.pow(N as u32), GridSpaceInterpolation(lerps))
+        IntoGridSpace::new(steps.pow(N as u32), GridSpaceInterpolation::new(lerps))
     }
 }
 
 #[derive(Clone, Copy, Debug)]
-pub struct GridSpaceInterpolation<T, const N: usize>(pub [IntoSpace<LinearInterpolation<T>>; N]);
+pub struct GridSpaceInterpolation<T, const N: usize>(
+    pub [IntoSpace<LinearInterpolation<T>>; N],
+    Odometer<N>,
+);
+
+impl<T, const N: usize> GridSpaceInterpolation<T, N> {
+    pub(crate) fn new(spaces: [IntoSpace<LinearInterpolation<T>>; N]) -> Self {
+        GridSpaceInterpolation(spaces, Odometer::new())
+    }
+}
+
+impl<T, const N: usize> GridInterpolation<N> for GridSpaceInterpolation<T, N> {
+    fn lens(&self) -> [usize; N] {
+        self.0.each_ref().map(|space| space.len)
+    }
+
+    fn odometer_mut(&mut self) -> &mut Odometer<N> {
+        &mut self.1
+    }
+}
+
+impl<T: Copy, const N: usize> GridSpaceInterpolation<T, N>
+where
+    LinearInterpolation<T>: Interpolate<Item = T>,
+{
+    fn at(&self, index: [usize; N]) -> [T; N] {
+        core::array::from_fn(|i| self.0[i].interpolate.interpolate(index[i]))
+    }
+}
 
-impl<T, const N: usize> Interpolate for GridSpaceInterpolation<T, N>
+impl<T: Copy, const N: usize> Interpolate for GridSpaceInterpolation<T, N>
 where
     LinearInterpolation<T>: Interpolate<Item = T>,
 {
     type Item = [T; N];
-    fn interpolate(self, mut x: usize) -> [T; N] {
-        self.0.map(|space| {
-            let z = x % space.len;
-            x /= space.len;
-            space.interpolate.interpolate(z)
-        })
+    fn interpolate(self, x: usize) -> [T; N] {
+        let index = self.1.split(x, self.lens());
+        self.at(index)
+    }
+
+    fn interpolate_next(&mut self, x: usize) -> [T; N] {
+        let index = self.1.next(x, self.lens());
+        self.at(index)
+    }
+
+    fn interpolate_next_back(&mut self, x: usize) -> [T; N] {
+        let index = self.1.next_back(x, self.lens());
+        self.at(index)
+    }
+
+    fn fill(self, start: usize, out: &mut [[T; N]]) {
+        // split the start index once, then count up through the axes
+        // instead of dividing for every value
+        let mut odometer = self.1;
+        for (x, out) in (start..).zip(out) {
+            let index = odometer.next(x, self.lens());
+            *out = self.at(index);
+        }
+    }
+}
+
+impl<T: Copy, const N: usize> GridSpace<T, N> {
+    /// The first point of the grid, before any values were taken from the iterator
+    ///
+    /// ```
+    /// use iter_num_tools::{arange_grid, grid_space};
+    ///
+    /// let it = grid_space([0.0, 1.0]..=[1.0, 2.0], [3, 5]);
+    /// assert_eq!(it.origin(), [0.0, 1.0]);
+    /// assert_eq!(it.step(), [0.5, 0.25]);
+    /// assert_eq!(it.steps(), [3, 5]);
+    ///
+    /// let it = arange_grid([0.0, 0.0]..[1.0, 2.0], 0.5);
+    /// assert_eq!(it.step(), [0.5, 0.5]);
+    /// assert_eq!(it.steps(), [2, 4]);
+    /// ```
+    pub fn origin(&self) -> [T; N] {
+        self.interpolate.0.map(|space| space.interpolate.start)
+    }
+
+    /// The difference between consecutive values along each axis
+    pub fn step(&self) -> [T; N] {
+        self.interpolate.0.map(|space| space.interpolate.step)
+    }
+
+    /// The number of values along each axis
+    pub fn steps(&self) -> [usize; N] {
+        self.interpolate.lens()
+    }
+}
+
+impl<T: Real + LinSpaceNum, const N: usize> GridSpace<T, N> {
+    /// The mean of the points left in the iterator, computed without iterating.
+    /// Returns `None` if the iterator is empty
+    ///
+    /// ```
+    /// use iter_num_tools::grid_space;
+    ///
+    /// let it = grid_space([0.0, 0.0]..=[1.0, 2.0], [101, 1001]);
+    /// assert_eq!(it.centroid(), Some([0.5, 1.0]));
+    /// ```
+    pub fn centroid(&self) -> Option<[T; N]> {
+        let Range { start, end } = self.range;
+        if start == end {
+            return None;
+        }
+        let n = T::from_usize(end - start)?;
+
+        let odometer = self.interpolate.1;
+        let strides = odometer.strides(self.interpolate.lens());
+        let mut centroid = [T::zero(); N];
+        for ((c, space), stride) in centroid.iter_mut().zip(self.interpolate.0).zip(strides) {
+            // the sum along each axis of the points before `start` and `end`,
+            // each made of whole passes over the axis and then a partial one
+            let prefix_sum = |x: usize| -> Option<T> {
+                let len = space.len;
+                let period = stride * len;
+                let (passes, rem) = (x / period, x % period);
+                let (whole, part) = (rem / stride, rem % stride);
+                let lerp = space.interpolate;
+                // a partial pass that goes backwards has covered the end of the axis
+                let covered = if odometer.is_backwards(passes) {
+                    len - whole..len
+                } else {
+                    0..whole
+                };
+                let mut sum = T::from_usize(passes * stride)? * lerp.sum_range(0..len)?
+                    + T::from_usize(stride)? * lerp.sum_range(covered)?;
+                if part > 0 {
+                    let z = odometer.axis_index(x / stride, len);
+                    sum = sum + T::from_usize(part)? * lerp.interpolate(z);
+                }
+                Some(sum)
+            };
+            *c = (prefix_sum(end)? - prefix_sum(start)?) / n;
+        }
+        Some(centroid)
+    }
+}
+
+impl<T: Copy, const N: usize> GridSpace<T, N>
+where
+    LinearInterpolation<T>: Interpolate<Item = T>,
+{
+    /// Write the next values of the iterator into one slice per axis, returning how many were written.
+    /// This is fewer than the shortest slice only if the iterator runs out of values first.
+    ///
+    /// This is the structure-of-arrays counterpart to [`fill_into`](Space::fill_into).
+    /// Each axis is written in runs of equal values, or runs of evenly spaced values
+    /// along the fastest varying axis, which the compiler can vectorize.
+    ///
+    /// ```
+    /// use iter_num_tools::grid_space;
+    ///
+    /// let mut it = grid_space([0.0, 0.0]..=[1.0, 2.0], [3, 2]);
+    /// let mut xs = [0.0; 6];
+    /// let mut ys = [0.0; 6];
+    ///
+    /// assert_eq!(it.fill_axes_into([&mut xs, &mut ys]), 6);
+    /// assert_eq!(xs, [0.0, 0.5, 1.0, 0.0, 0.5, 1.0]);
+    /// assert_eq!(ys, [0.0, 0.0, 0.0, 2.0, 2.0, 2.0]);
+    /// ```
+    pub fn fill_axes_into(&mut self, axes: [&mut [T]; N]) -> usize {
+        let n = axes.iter().fold(self.len(), |n, axis| n.min(axis.len()));
+        let start = self.range.start;
+
+        let odometer = self.interpolate.1;
+        let strides = odometer.strides(self.interpolate.lens());
+        for ((axis, space), stride) in axes.into_iter().zip(self.interpolate.0).zip(strides) {
+            let mut out = &mut axis[..n];
+            let mut x = start;
+            while !out.is_empty() {
+                let z = odometer.axis_index(x / stride, space.len);
+                let run = if stride == 1 {
+                    space.len - x % space.len
+                } else {
+                    stride - x % stride
+                };
+                let (head, tail) = out.split_at_mut(run.min(out.len()));
+                if stride != 1 {
+                    head.fill(space.interpolate.interpolate(z));
+                } else if odometer.is_backwards(x / space.len) {
+                    space.interpolate.fill(z + 1 - head.len(), head);
+                    head.reverse();
+                } else {
+                    space.interpolate.fill(z, head);
+                }
+                x += head.len();
+                out = tail;
+            }
+        }
+
+        self.range.start += n;
+        n
     }
 }
 
@@ -149,7 +332,7 @@ pub type IntoGridSpace<T, const N: usize> = IntoSpace<GridSpaceInterpolation<T,
 
 #[cfg(test)]
 mod tests {
-    use crate::check_double_ended_iter;
+    use crate::{advance, check_double_ended_iter, GridOrder};
 
     use super::*;
 
@@ -220,6 +403,248 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_grid_get() {
+        let mut it = grid_space([0.0, 0.0]..[1.0, 2.0], [2, 4]);
+        assert_eq!(it.get(3), Some([0.5, 0.5]));
+        assert_eq!(it.get(8), None);
+        it.nth(2);
+        assert_eq!(it.peek(), Some([0.5, 0.5]));
+        assert_eq!(it.get(0), it.clone().next());
+        assert_eq!(it.peek_back(), it.next_back());
+    }
+
+    #[test]
+    fn test_grid_space_fill_into() {
+        let it = grid_space([0.0, 0.0, 0.0]..=[1.0, 2.0, 3.0], [3, 4, 5]);
+        let expected: Vec<[f64; 3]> = it.clone().collect();
+        for skip in [0, 1, 7, 59, 60] {
+            let mut it = advance(it.clone(), skip);
+            let mut buf = [[0.0; 3]; 64];
+            let n = it.fill_into(&mut buf);
+            assert_eq!(buf[..n], expected[skip..]);
+            assert_eq!(it.next(), None);
+        }
+    }
+
+    #[test]
+    fn test_grid_space_fill_axes_into() {
+        let it = grid_space([0.0, 0.0, 0.0]..=[1.0, 2.0, 3.0], [3, 4, 5]);
+        let expected: Vec<[f64; 3]> = it.clone().collect();
+        for skip in [0, 1, 7, 59, 60] {
+            let mut it = advance(it.clone(), skip);
+            let mut xs = [0.0; 64];
+            let mut ys = [0.0; 64];
+            let mut zs = [0.0; 40];
+            let n = it.fill_axes_into([&mut xs, &mut ys, &mut zs]);
+            assert_eq!(n, (60 - skip).min(40));
+            for (i, point) in expected[skip..skip + n].iter().enumerate() {
+                assert_eq!([xs[i], ys[i], zs[i]], *point);
+            }
+            assert_eq!(it.len(), 60 - skip - n);
+        }
+    }
+
+    #[test]
+    fn test_grid_space_mixed_stepping() {
+        let it = grid_space([0.0, 0.0, 0.0, 0.0]..=[1.0, 2.0, 3.0, 4.0], [3, 1, 4, 2]);
+        let expected: Vec<[f64; 4]> = (0..it.len()).map(|i| it.get(i).unwrap()).collect();
+        check_double_ended_iter(it.clone(), <[_; 24]>::try_from(expected.clone()).unwrap());
+
+        // random access in between stepping keeps both ends in sync
+        let mut it = it;
+        let mut front = 0;
+        let mut back = expected.len();
+        for n in [0, 2, 0, 5, 1] {
+            front += n + 1;
+            assert_eq!(it.nth(n), Some(expected[front - 1]));
+            assert_eq!(it.next(), Some(expected[front]));
+            front += 1;
+            back -= 1;
+            assert_eq!(it.next_back(), Some(expected[back]));
+        }
+        assert!(it.eq(expected[front..back].iter().copied()));
+    }
+
+    #[test]
+    fn test_grid_space_centroid() {
+        let it = grid_space([0.0, -1.0, 2.0]..[1.0, 3.0, 5.0], [4, 3, 5]);
+        for (skip, skip_back) in [(0, 0), (1, 0), (0, 1), (5, 7), (13, 29), (59, 0), (30, 29)] {
+            let mut it = advance(it.clone(), skip);
+            if skip_back > 0 {
+                it.nth_back(skip_back - 1);
+            }
+            let points: Vec<[f64; 3]> = it.clone().collect();
+            let centroid = it.centroid().unwrap();
+            for axis in 0..3 {
+                let mean = points.iter().map(|p| p[axis]).sum::<f64>() / points.len() as f64;
+                assert!(
+                    (centroid[axis] - mean).abs() < 1e-12,
+                    "{skip} {skip_back} {axis}"
+                );
+            }
+        }
+
+        let mut it = grid_space([0.0, 0.0]..[1.0, 1.0], 2);
+        it.nth(3);
+        assert_eq!(it.centroid(), None);
+    }
+
+    #[test]
+    fn test_grid_space_accessors() {
+        let mut it = grid_space([1.0, -2.0, 0.0]..[2.0, 2.0, 3.0], [4, 8, 3]);
+        it.nth(9);
+        assert_eq!(it.origin(), [1.0, -2.0, 0.0]);
+        assert_eq!(it.step(), [0.25, 0.5, 1.0]);
+        assert_eq!(it.steps(), [4, 8, 3]);
+        assert_eq!(it.remaining_range(), 10..96);
+
+        // the step is the exact spacing between neighbouring points
+        let [dx, _, _] = it.step();
+        let [a, b] = [it.get(0).unwrap(), it.get(1).unwrap()];
+        assert_eq!(b[0] - a[0], dx);
+    }
+
+    #[test]
+    fn test_grid_space_order() {
+        let xs = [0.0, 0.5];
+        let ys = [1.0, 2.0, 3.0];
+        let zs = [-1.0, 1.0];
+        let it = grid_space([0.0, 1.0, -1.0]..=[0.5, 3.0, 1.0], [2, 3, 2]);
+
+        for axes in [[0, 1, 2], [2, 1, 0], [1, 2, 0], [2, 0, 1]] {
+            // visit the points with nested loops, the last axis in `axes` outermost
+            let mut expected = vec![];
+            let lens = [2, 3, 2];
+            for k in 0..lens[axes[2]] {
+                for j in 0..lens[axes[1]] {
+                    for i in 0..lens[axes[0]] {
+                        let mut index = [0; 3];
+                        index[axes[0]] = i;
+                        index[axes[1]] = j;
+                        index[axes[2]] = k;
+                        expected.push([xs[index[0]], ys[index[1]], zs[index[2]]]);
+                    }
+                }
+            }
+
+            let it = it.clone().with_order(GridOrder::Axes(axes));
+            check_double_ended_iter(it.clone(), <[_; 12]>::try_from(expected.clone()).unwrap());
+            for (i, point) in expected.iter().enumerate() {
+                assert_eq!(it.get(i), Some(*point));
+            }
+
+            let mut buf = [[0.0; 3]; 12];
+            let mut filled = it.clone();
+            filled.next();
+            assert_eq!(filled.fill_into(&mut buf), 11);
+            assert_eq!(buf[..11], expected[1..]);
+
+            let mut axes_out = [[0.0; 12]; 3];
+            let [a, b, c] = &mut axes_out;
+            let mut filled = it.clone();
+            filled.nth(2);
+            assert_eq!(filled.fill_axes_into([a, b, c]), 9);
+            for (i, point) in expected[3..].iter().enumerate() {
+                assert_eq!([axes_out[0][i], axes_out[1][i], axes_out[2][i]], *point);
+            }
+
+            let mut partial = it;
+            partial.nth(4);
+            let centroid = partial.centroid().unwrap();
+            for axis in 0..3 {
+                let mean = expected[5..].iter().map(|p| p[axis]).sum::<f64>() / 7.0;
+                assert!((centroid[axis] - mean).abs() < 1e-12);
+            }
+        }
+
+        assert!(it
+            .clone()
+            .with_order(GridOrder::RowMajor)
+            .eq(it.with_order(GridOrder::Axes([2, 1, 0]))));
+    }
+
+    #[test]
+    fn test_grid_space_serpentine() {
+        for order in [
+            GridOrder::ColumnMajor,
+            GridOrder::RowMajor,
+            GridOrder::Axes([1, 2, 0]),
+        ] {
+            let it = grid_space([0.0, 0.0, 0.0]..=[2.0, 2.0, 1.0], [3, 3, 2])
+                .with_order(order)
+                .serpentine();
+            let points: Vec<[f64; 3]> = it.clone().collect();
+
+            // the same points as the plain grid, one step apart
+            let mut sorted = points.clone();
+            sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            let mut plain: Vec<[f64; 3]> =
+                grid_space([0.0, 0.0, 0.0]..=[2.0, 2.0, 1.0], [3, 3, 2]).collect();
+            plain.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            assert_eq!(sorted, plain);
+            assert_ne!(points, sorted);
+            for pair in points.windows(2) {
+                let moved: f64 = (0..3).map(|i| (pair[0][i] - pair[1][i]).abs()).sum();
+                assert_eq!(moved, 1.0, "{pair:?}");
+            }
+
+            // stepping, random access and the bulk methods all agree
+            check_double_ended_iter(it.clone(), <[_; 18]>::try_from(points.clone()).unwrap());
+            for (i, point) in points.iter().enumerate() {
+                assert_eq!(it.get(i), Some(*point));
+            }
+            for skip in [0, 1, 4, 7, 17] {
+                let rest = advance(it.clone(), skip);
+                let expected = &points[skip..];
+
+                let mut buf = [[0.0; 3]; 18];
+                let n = rest.clone().fill_into(&mut buf);
+                assert_eq!(buf[..n], *expected);
+
+                let mut axes_out = [[0.0; 18]; 3];
+                let [a, b, c] = &mut axes_out;
+                let n = rest.clone().fill_axes_into([a, b, c]);
+                for (i, point) in expected.iter().enumerate().take(n) {
+                    assert_eq!([axes_out[0][i], axes_out[1][i], axes_out[2][i]], *point);
+                }
+
+                let centroid = rest.centroid().unwrap();
+                for axis in 0..3 {
+                    let mean =
+                        expected.iter().map(|p| p[axis]).sum::<f64>() / expected.len() as f64;
+                    assert!((centroid[axis] - mean).abs() < 1e-12);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_grid_space_curves() {
+        let grid = grid_space([0.0, 0.0, 0.0]..[3.0, 5.0, 2.0], [3, 5, 2]);
+        let mut plain: Vec<[f64; 3]> = grid.clone().collect();
+        plain.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        // the same points as the plain grid, from either end
+        for it in [grid.clone().morton(), grid.clone().hilbert()] {
+            assert_eq!(it.len(), 30);
+            let mut points: Vec<[f64; 3]> = it.clone().collect();
+            let mut back: Vec<[f64; 3]> = it.rev().collect();
+            back.reverse();
+            assert_eq!(points, back);
+            points.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            assert_eq!(points, plain);
+        }
+
+        // a power of two hilbert curve only moves a single step at a time
+        let points: Vec<[f64; 2]> = grid_space([0.0, 0.0]..[8.0, 8.0], 8).hilbert().collect();
+        assert_eq!(points.len(), 64);
+        for pair in points.windows(2) {
+            let moved: f64 = (0..2).map(|i| (pair[0][i] - pair[1][i]).abs()).sum();
+            assert_eq!(moved, 1.0, "{pair:?}");
+        }
+    }
+
     #[test]
     fn test_grid_space_exclusive_len() {
         let mut it = grid_space([0.0, 0.0]..[1.0, 2.0], [2, 4]);
@@ -1,6 +1,7 @@
 use array_bin_ops::Array;
 
 use crate::{
+    odometer::{GridInterpolation, Odometer},
     space::{Interpolate, IntoSpace, Space},
     step::Step,
 };
@@ -65,7 +66,7 @@ impl<T: Step, const N: usize> ToGridStep<N> for Range<[T; N]> {
             (start, steps)
         });
         IntoGridStep {
-            interpolate: GridStepInterpolation(steps),
+            interpolate: GridStepInterpolation::new(steps),
             len,
         }
     }
@@ -83,26 +84,55 @@ impl<T: Step, const N: usize> ToGridStep<N> for RangeInclusive<[T; N]> {
             (start, steps)
         });
         IntoGridStep {
-            interpolate: GridStepInterpolation(steps),
+            interpolate: GridStepInterpolation::new(steps),
             len,
         }
     }
 }
 
 #[derive(Clone, Copy, Debug)]
-pub struct GridStepInterpolation<T, const N: usize>(pub [(T, usize); N]);
+pub struct GridStepInterpolation<T, const N: usize>(pub [(T, usize); N], Odometer<N>);
+
+impl<T, const N: usize> GridStepInterpolation<T, N> {
+    pub(crate) fn new(steps: [(T, usize); N]) -> Self {
+        GridStepInterpolation(steps, Odometer::new())
+    }
+}
+
+impl<T, const N: usize> GridInterpolation<N> for GridStepInterpolation<T, N> {
+    fn lens(&self) -> [usize; N] {
+        self.0.each_ref().map(|space| space.1)
+    }
+
+    fn odometer_mut(&mut self) -> &mut Odometer<N> {
+        &mut self.1
+    }
+}
+
+impl<T: Step, const N: usize> GridStepInterpolation<T, N> {
+    fn at(&self, index: [usize; N]) -> [T; N] {
+        core::array::from_fn(|i| T::forward(self.0[i].0.clone(), index[i]).unwrap())
+    }
+}
 
 impl<T, const N: usize> Interpolate for GridStepInterpolation<T, N>
 where
     T: Step,
 {
     type Item = [T; N];
-    fn interpolate(self, mut x: usize) -> [T; N] {
-        self.0.map(|space| {
-            let z = x % space.1;
-            x /= space.1;
-            T::forward(space.0, z).unwrap()
-        })
+    fn interpolate(self, x: usize) -> [T; N] {
+        let index = self.1.split(x, self.lens());
+        self.at(index)
+    }
+
+    fn interpolate_next(&mut self, x: usize) -> [T; N] {
+        let index = self.1.next(x, self.lens());
+        self.at(index)
+    }
+
+    fn interpolate_next_back(&mut self, x: usize) -> [T; N] {
+        let index = self.1.next_back(x, self.lens());
+        self.at(index)
     }
 }
 
@@ -112,9 +142,29 @@ pub type GridStep<T, const N: usize> = Space<GridStepInterpolation<T, N>>;
 /// [`IntoIterator`] returned by [`ToGridSpace::into_grid_space`]
 pub type IntoGridStep<T, const N: usize> = IntoSpace<GridStepInterpolation<T, N>>;
 
+impl<T: Clone, const N: usize> GridStep<T, N> {
+    /// The first point of the grid, before any values were taken from the iterator
+    ///
+    /// ```
+    /// use iter_num_tools::grid_step;
+    ///
+    /// let it = grid_step([1, 2]..=[3, 3]);
+    /// assert_eq!(it.origin(), [1, 2]);
+    /// assert_eq!(it.steps(), [3, 2]);
+    /// ```
+    pub fn origin(&self) -> [T; N] {
+        self.interpolate.0.clone().map(|(start, _)| start)
+    }
+
+    /// The number of values along each axis
+    pub fn steps(&self) -> [usize; N] {
+        self.interpolate.lens()
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::check_double_ended_iter;
+    use crate::{check_double_ended_iter, check_meet_in_middle, GridOrder};
 
     use super::*;
 
@@ -152,6 +202,17 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_grid_get() {
+        let mut it = grid_step([0, 0]..[2, 4]);
+        assert_eq!(it.get(3), Some([1, 1]));
+        assert_eq!(it.get(8), None);
+        it.nth(2);
+        assert_eq!(it.peek(), Some([1, 1]));
+        assert_eq!(it.get(0), it.clone().next());
+        assert_eq!(it.peek_back(), it.next_back());
+    }
+
     #[test]
     fn test_grid_space_exclusive_len() {
         let mut it = grid_step([0, 0]..[2, 4]);
@@ -171,4 +232,182 @@ mod tests {
 
         assert_eq!(it.len(), expected_len);
     }
+
+    #[test]
+    fn test_grid_step_mixed_stepping() {
+        let mut it = grid_step([0, 0, 0]..[3, 2, 2]);
+        assert_eq!(it.next(), Some([0, 0, 0]));
+        assert_eq!(it.next(), Some([1, 0, 0]));
+        assert_eq!(it.next_back(), Some([2, 1, 1]));
+        assert_eq!(it.nth(1), Some([0, 1, 0]));
+        assert_eq!(it.next(), Some([1, 1, 0]));
+        assert_eq!(it.nth_back(2), Some([2, 0, 1]));
+        assert_eq!(it.next_back(), Some([1, 0, 1]));
+        check_double_ended_iter(it, [[2, 1, 0], [0, 0, 1]]);
+    }
+
+    #[test]
+    fn test_grid_step_order() {
+        let it = grid_step([0, 0]..[2, 3]).with_order(GridOrder::RowMajor);
+        check_double_ended_iter(it.clone(), [[0, 0], [0, 1], [0, 2], [1, 0], [1, 1], [1, 2]]);
+        assert_eq!(it.clone().nth(4), Some([1, 1]));
+        assert_eq!(it.origin(), [0, 0]);
+
+        let mut it = grid_step([0, 0, 0]..=[1, 1, 1]).with_order(GridOrder::Axes([2, 0, 1]));
+        assert_eq!(it.next(), Some([0, 0, 0]));
+        assert_eq!(it.next(), Some([0, 0, 1]));
+        assert_eq!(it.next(), Some([1, 0, 0]));
+        assert_eq!(it.next_back(), Some([1, 1, 1]));
+        assert_eq!(it.next_back(), Some([1, 1, 0]));
+        assert_eq!(it.nth_back(0), Some([0, 1, 1]));
+        check_double_ended_iter(it, [[1, 0, 1], [0, 1, 0]]);
+    }
+
+    #[test]
+    #[should_panic = "not a permutation"]
+    fn test_grid_step_invalid_order() {
+        let _ = grid_step([0, 0]..[2, 3]).with_order(GridOrder::Axes([0, 0]));
+    }
+
+    #[test]
+    #[should_panic = "must be set before any values are taken"]
+    fn test_grid_step_order_after_next() {
+        let mut it = grid_step([0, 0]..[3, 2]);
+        it.next();
+        it.next();
+        let _ = it.with_order(GridOrder::RowMajor);
+    }
+
+    #[test]
+    #[should_panic = "must be set before any values are taken"]
+    fn test_grid_step_serpentine_after_next_back() {
+        let mut it = grid_step([0, 0]..[3, 2]);
+        it.next_back();
+        let _ = it.serpentine();
+    }
+
+    #[test]
+    fn test_grid_step_serpentine() {
+        let mut it = grid_step([0, 0, 0]..=[1, 2, 1]).serpentine();
+        assert_eq!(it.len(), 12);
+        assert_eq!(it.nth(5), Some([1, 2, 0]));
+        assert_eq!(it.next(), Some([1, 2, 1]));
+        assert_eq!(it.next_back(), Some([0, 0, 1]));
+        assert_eq!(it.nth_back(1), Some([1, 1, 1]));
+        check_double_ended_iter(it, [[0, 2, 1], [0, 1, 1]]);
+    }
+
+    #[test]
+    fn test_grid_step_curves() {
+        let grid = grid_step([0, 0]..[3, 3]);
+        check_double_ended_iter(
+            grid.clone().morton(),
+            [
+                [0, 0],
+                [1, 0],
+                [0, 1],
+                [1, 1],
+                [2, 0],
+                [2, 1],
+                [0, 2],
+                [1, 2],
+                [2, 2],
+            ],
+        );
+        check_double_ended_iter(
+            grid.clone().hilbert(),
+            [
+                [0, 0],
+                [1, 0],
+                [1, 1],
+                [0, 1],
+                [0, 2],
+                [1, 2],
+                [2, 2],
+                [2, 1],
+                [2, 0],
+            ],
+        );
+
+        let mut it = grid.hilbert();
+        assert_eq!(it.len(), 9);
+        assert_eq!(it.next_back(), Some([2, 0]));
+        assert_eq!(it.nth(2), Some([1, 1]));
+        assert_eq!(it.len(), 5);
+        check_double_ended_iter(it, [[0, 1], [0, 2], [1, 2], [2, 2], [2, 1]]);
+
+        // the curve replaces the old order
+        let it = grid_step([0, 0, 0]..[4, 4, 4]).serpentine();
+        let mut points: Vec<_> = it.hilbert().collect();
+        assert_eq!(points.len(), 64);
+        points.sort();
+        assert!(points
+            .into_iter()
+            .eq(grid_step([0, 0, 0]..[4, 4, 4]).with_order(GridOrder::RowMajor)));
+
+        assert_eq!(grid_step([0, 0]..[0, 5]).morton().next(), None);
+    }
+
+    #[test]
+    fn test_grid_step_spiral() {
+        let it = grid_step([-3i32, -3]..=[3, 3]).spiral();
+        assert_eq!(it.len(), 49);
+        for (ring, [x, y]) in it.clone().rings() {
+            assert_eq!(ring, x.unsigned_abs().max(y.unsigned_abs()) as usize);
+        }
+        let mut points: Vec<_> = it.collect();
+        points.sort();
+        assert!(points
+            .into_iter()
+            .eq(grid_step([-3, -3]..=[3, 3]).with_order(GridOrder::RowMajor)));
+
+        // even lengths round the centre down
+        let mut it = grid_step([0, 0]..[4, 2]).spiral().rings();
+        assert_eq!(it.next(), Some((0, [1, 0])));
+        check_double_ended_iter(
+            it,
+            [
+                (1, [2, 0]),
+                (1, [2, 1]),
+                (1, [1, 1]),
+                (1, [0, 1]),
+                (1, [0, 0]),
+                (2, [3, 0]),
+                (2, [3, 1]),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_grid_step_wavefront() {
+        let grid = grid_step([0, 0, 0]..[3, 2, 4]);
+        let it = grid.clone().wavefront();
+        assert_eq!(it.len(), 24);
+        let points: Vec<_> = it.clone().collect();
+        for pair in points.windows(2) {
+            let [a, b] = [pair[0], pair[1]].map(|p| p.iter().sum::<i32>());
+            assert!(a <= b, "{pair:?}");
+        }
+        let mut sorted = points.clone();
+        sorted.sort();
+        assert!(sorted
+            .into_iter()
+            .eq(grid.clone().with_order(GridOrder::RowMajor)));
+
+        check_meet_in_middle(it, &points);
+
+        // the wavefronts together are the whole wavefront order, from either end
+        let wavefronts = grid.clone().wavefronts();
+        assert_eq!(wavefronts.len(), 7);
+        let lens: Vec<_> = wavefronts.clone().map(|w| w.len()).collect();
+        assert_eq!(lens, [1, 3, 5, 6, 5, 3, 1]);
+        assert!(wavefronts.clone().flatten().eq(points.iter().copied()));
+        assert!(wavefronts
+            .rev()
+            .flat_map(|w| w.rev())
+            .eq(points.iter().rev().copied()));
+
+        assert_eq!(grid_step([0, 0]..[3, 0]).wavefront().next(), None);
+        assert_eq!(grid_step([0, 0]..[3, 0]).wavefronts().len(), 0);
+    }
 }
@@ -57,6 +57,20 @@
 //! ]));
 //! ```
 //!
+//! The first axis varies fastest by default. Use [`GridOrder`] to visit the points in another order,
+//! or [`GridSpace::hilbert`], [`GridSpace::morton`], [`GridSpace::spiral`] and [`GridSpace::wavefront`] for other traversals
+//!
+//! ```rust
+//! use iter_num_tools::{grid_space, GridOrder};
+//!
+//! let it = grid_space([0.0, 0.0]..=[1.0, 2.0], 3).with_order(GridOrder::RowMajor);
+//! assert!(it.eq([
+//!     [0.0, 0.0], [0.0, 1.0], [0.0, 2.0],
+//!     [0.5, 0.0], [0.5, 1.0], [0.5, 2.0],
+//!     [1.0, 0.0], [1.0, 1.0], [1.0, 2.0],
+//! ]));
+//! ```
+//!
 //! ## Arange
 //!
 //! Arange is similar to [LinSpace](#linspace), but instead of a fixed amount of steps, it steps by a fixed amount.
@@ -66,18 +80,33 @@
 //!
 //! let it = arange(0.0..2.0, 0.5);
 //! assert!(it.eq([0.0, 0.5, 1.0, 1.5]));
+//!
+//! // negative steps count down
+//! let it = arange(2.0..0.0, -0.5);
+//! assert!(it.eq([2.0, 1.5, 1.0, 0.5]));
 //! ```
 //!
 //! #### Note
 //!
-//! There is no inclusive version of arange. Consider the following
+//! Inclusive ranges need some care. Consider the following
 //!
 //! ```rust,ignore
 //! use iter_num_tools::arange;
 //! let it = arange(0.0..=2.1, 0.5);
 //! ```
 //!
-//! We would not expect 2.1 to ever be a value that the iterator will ever meet, but the range suggests it should be included. Therefore, no RangeInclusive implementation is provided.
+//! We would not expect 2.1 to ever be a value that the iterator will ever meet, but the range suggests it should be included. Therefore, a RangeInclusive needs to be given a `Tolerance` alongside the step, to decide how close the end has to be to a step boundary to be included. When it is included, the end value is yielded exactly.
+//!
+//! ```rust
+//! use iter_num_tools::{arange, Tolerance};
+//!
+//! let it = arange(0.0..=2.0, (0.5, Tolerance::Ulps(4)));
+//! assert!(it.eq([0.0, 0.5, 1.0, 1.5, 2.0]));
+//!
+//! // 0.1 * 3 is not exactly 0.3, but 0.3 is still yielded at the end
+//! let it = arange(0.0..=0.3, (0.1, Tolerance::Ulps(4)));
+//! assert_eq!(it.last(), Some(0.3));
+//! ```
 //!
 //! ## ArangeGrid
 //!
@@ -113,7 +142,7 @@
 //! LogSpace is similar to [LinSpace](#linspace), but instead of evenly spaced linear steps, it has evenly spaced logarithmic steps.
 //!
 //! ```rust
-//! use iter_num_tools::log_space;
+//! use iter_num_tools::{log_space, log_space_base};
 //! use itertools::zip_eq;
 //!
 //! // From 1.0 up to and including 1000.0, taking 4 logarithmic steps
@@ -127,33 +156,128 @@
 //! let expected: [f64; 3] = [1.0, 10.0, 100.0];
 //!
 //! assert!(zip_eq(it, expected).all(|(x, y)| (x-y).abs() < 1e-10));
+//!
+//! // Or from exponents, 10^0 up to and including 10^3
+//! let it = log_space_base(0.0..=3.0, 4, 10.0);
+//! let expected: [f64; 4] = [1.0, 10.0, 100.0, 1000.0];
+//!
+//! assert!(zip_eq(it, expected).all(|(x, y)| (x-y).abs() < 1e-10));
 //! ```
+//!
+//! ## GeomSpace
+//!
+//! GeomSpace is the same as [LogSpace](#logspace), but also works for ranges that are entirely negative. The ends of the range are always hit exactly.
+//!
+//! ```rust
+//! use iter_num_tools::geom_space;
+//! use itertools::zip_eq;
+//!
+//! // From -1.0 down to and including -1000.0, taking 4 logarithmic steps
+//! let it = geom_space(-1.0..=-1000.0, 4);
+//! let expected: [f64; 4] = [-1.0, -10.0, -100.0, -1000.0];
+//!
+//! assert!(zip_eq(it, expected).all(|(x, y)| (x-y).abs() < 1e-10));
+//! ```
+//!
+//! ## GeomStep
+//!
+//! GeomStep is to [GeomSpace](#geomspace) what [Arange](#arange) is to [LinSpace](#linspace). Instead of a fixed amount of steps, it multiplies by a fixed ratio.
+//!
+//! ```rust
+//! use iter_num_tools::geom_step;
+//!
+//! let it = geom_step(1.0..100.0, 3.0);
+//! assert!(it.eq([1.0, 3.0, 9.0, 27.0, 81.0]));
+//! ```
+//!
+//! ## Custom spaces
+//!
+//! All of the iterators above are a [`Space`] over some [`Interpolate`] implementation.
+//! You can provide your own mapping from index to value and get the same iterator behaviour.
+//!
+//! ```rust
+//! use iter_num_tools::{Interpolate, IntoSpace};
+//!
+//! #[derive(Clone, Copy)]
+//! struct Powers(u32);
+//!
+//! impl Interpolate for Powers {
+//!     type Item = u32;
+//!     fn interpolate(self, x: usize) -> u32 {
+//!         self.0.pow(x as u32)
+//!     }
+//! }
+//!
+//! let it = IntoSpace::new(4, Powers(3)).into_space();
+//! assert_eq!(it.len(), 4);
+//! assert!(it.eq([1, 3, 9, 27]));
+//! ```
+//!
+//! ## Parallel iteration
+//!
+//! With the `rayon` feature enabled, every space implements `IntoParallelIterator`
+//! as an `IndexedParallelIterator`, split between threads by index range.
+//!
+//! ## Errors
+//!
+//! With the `std` feature enabled, [`SpaceError`] implements `std::error::Error`.
+//! Without it the crate is `no_std`.
 #![warn(missing_docs)]
 #![cfg_attr(feature = "trusted_len", feature(trusted_len))]
 #![cfg_attr(feature = "iter_advance_by", feature(iter_advance_by))]
+#![cfg_attr(feature = "try_trait_v2", feature(try_trait_v2))]
 #![cfg_attr(not(test), no_std)]
 
+#[cfg(feature = "std")]
+extern crate std;
+
 #[cfg(test)]
 #[macro_use]
 extern crate pretty_assertions;
 
 mod arange;
 mod arange_grid;
+mod curve;
+mod error;
+mod geomspace;
+mod geomstep;
+mod grid_order;
 mod gridspace;
 mod gridstep;
 mod linspace;
 mod logspace;
+mod odometer;
+#[cfg(feature = "rayon")]
+mod par;
 mod space;
+mod spiral;
 mod step;
+mod wavefront;
 
-pub use arange::{arange, Arange, IntoArange, ToArange};
+pub use arange::{arange, try_arange, Arange, IntoArange, ToArange, Tolerance};
 pub use arange_grid::{arange_grid, ArangeGrid, IntoArangeGrid, ToArangeGrid};
+pub use curve::GridCurve;
+pub use error::SpaceError;
+pub use geomspace::{geom_space, try_geom_space, GeomSpace, IntoGeomSpace, ToGeomSpace};
+pub use geomstep::{geom_step, try_geom_step, GeomStep, IntoGeomStep, ToGeomStep};
+pub use grid_order::GridOrder;
 pub use gridspace::{grid_space, GridSpace, IntoGridSpace, ToGridSpace};
 pub use gridstep::{grid_step, GridStep, IntoGridStep, ToGridStep};
-pub use linspace::{lin_space, IntoLinSpace, LinSpace, ToLinSpace};
-pub use logspace::{log_space, IntoLogSpace, LogSpace, ToLogSpace};
+pub use linspace::{
+    lin_space, try_lin_space, IntoLinSpace, LinSpace, LinSpaceNum, Span, ToLinSpace,
+};
+pub use logspace::{
+    log_space, log_space_base, try_log_space, try_log_space_base, IntoLogSpace, LogSpace,
+    ToLogSpace, ToLogSpaceBase,
+};
+#[cfg(feature = "rayon")]
+pub use par::ParSpace;
+pub use space::{ChunksExact, Interpolate, IntoSpace, Space, Stride};
+pub use spiral::{GridSpiral, SpiralRings};
+pub use wavefront::{GridWavefront, GridWavefronts};
 
 #[cfg(test)]
+/// Asserts that `i` yields `expected` both forwards and in reverse
 #[track_caller]
 pub fn check_double_ended_iter<T: PartialEq + core::fmt::Debug, const N: usize>(
     i: impl DoubleEndedIterator<Item = T> + Clone,
@@ -167,6 +291,34 @@ pub fn check_double_ended_iter<T: PartialEq + core::fmt::Debug, const N: usize>(
     assert_eq!(actual, expected);
 }
 
+#[cfg(test)]
+/// Takes the first `n` values from `i`, to start the bulk methods part way through
+pub fn advance<I: Iterator>(mut i: I, n: usize) -> I {
+    if let Some(last) = n.checked_sub(1) {
+        i.nth(last);
+    }
+    i
+}
+
+#[cfg(test)]
+/// Asserts that `i` yields `expected` however the values are split between the front and the back
+#[track_caller]
+pub fn check_meet_in_middle<T: PartialEq + core::fmt::Debug>(
+    i: impl DoubleEndedIterator<Item = T> + ExactSizeIterator + Clone,
+    expected: &[T],
+) {
+    assert_eq!(i.len(), expected.len());
+    for split in 0..=expected.len() {
+        let mut it = i.clone();
+        let front: Vec<_> = it.by_ref().take(split).collect();
+        let mut back: Vec<_> = it.by_ref().rev().collect();
+        back.reverse();
+        assert_eq!(it.len(), 0);
+        assert_eq!(front.len(), split);
+        assert!(front.iter().chain(&back).eq(expected), "split at {split}");
+    }
+}
+
 // pub trait Array<const N: usize> {
 //     type Item;
 // }
@@ -1,9 +1,14 @@
-use crate::space::{Interpolate, IntoSpace, Space};
+use crate::{
+    error::{is_finite, SpaceError},
+    space::{Interpolate, IntoSpace, Space},
+};
 use core::ops::{Range, RangeInclusive};
-use num_traits::{FromPrimitive, Num};
+use num_traits::{real::Real, FromPrimitive, Num};
 
 /// Creates a linear space over range with a fixed number of steps
 ///
+/// For inclusive ranges, the first and last values are exactly the bounds of the range.
+///
 /// ```
 /// use iter_num_tools::lin_space;
 ///
@@ -11,10 +16,21 @@ use num_traits::{FromPrimitive, Num};
 /// let it = lin_space(20.0..=21.0, 3);
 /// assert!(it.eq(vec![20.0, 20.5, 21.0]));
 ///
+/// let it = lin_space(0.1..=0.7, 7);
+/// assert_eq!(it.last(), Some(0.7));
+///
+/// // Integers spread the remainder evenly between the steps
+/// let it = lin_space(0..=10, 4);
+/// assert!(it.eq(vec![0, 3, 6, 10]));
+///
 /// // Exclusive
 /// let it = lin_space(20.0..21.0, 2);
 /// assert!(it.eq(vec![20.0, 20.5]));
 /// ```
+///
+/// # Panics
+///
+/// If the number of steps does not fit in the item type. Use [`try_lin_space`] to handle this instead
 #[inline]
 pub fn lin_space<R>(range: R, steps: usize) -> LinSpace<R::Item>
 where
@@ -23,45 +39,323 @@ where
     range.into_lin_space(steps).into_space()
 }
 
+/// Creates a linear space over range with a fixed number of steps,
+/// returning an error instead of panicking if the space is invalid
+///
+/// ```
+/// use iter_num_tools::{try_lin_space, SpaceError};
+///
+/// let it = try_lin_space(20.0..=21.0, 3).unwrap();
+/// assert!(it.eq(vec![20.0, 20.5, 21.0]));
+///
+/// assert_eq!(try_lin_space(20.0..=21.0, 0).unwrap_err(), SpaceError::ZeroSteps);
+/// assert_eq!(try_lin_space(20.0..f64::NAN, 2).unwrap_err(), SpaceError::NonFiniteBound);
+/// ```
+pub fn try_lin_space<R>(range: R, steps: usize) -> Result<LinSpace<R::Item>, SpaceError>
+where
+    R: ToLinSpace,
+{
+    range.try_into_lin_space(steps).map(IntoSpace::into_space)
+}
+
 #[derive(Clone, Copy, Debug)]
 pub struct LinearInterpolation<T> {
     pub start: T,
     pub step: T,
+    pub end: T,
+    /// The index that `end` lands on, if it lands exactly on a step
+    pub end_index: Option<usize>,
+    /// Whether values past the midpoint are measured back from `end`, so that both ends are exact.
+    /// Otherwise every value before `end_index` is measured from `start`
+    pub from_end: bool,
+    /// For integer types, the distance from `start` to `end`, which is spread evenly over the steps.
+    /// The values are computed from this rather than `step`, so they cannot overflow
+    pub span: Option<Span>,
+}
+
+/// The distance between the bounds of an integer space, which always fits in a `u128`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Span {
+    /// Whether `end` is below `start`
+    pub descending: bool,
+    /// How far `end` is from `start`
+    pub magnitude: u128,
 }
 
+/// The numbers that a [`lin_space`] can be made over.
+///
+/// Integers spread the distance between the bounds evenly over the steps, carrying the remainder,
+/// so that an inclusive space lands exactly on `end` without overflowing along the way.
+/// Every other type divides the distance into equal steps, which is what the provided methods do,
+/// so a custom number type only needs an empty impl:
+///
+/// ```
+/// use iter_num_tools::LinSpaceNum;
+/// # #[derive(Clone, Copy, PartialEq)]
+/// # struct Fixed(i64);
+/// # impl core::ops::Add for Fixed { type Output = Self; fn add(self, o: Self) -> Self { Fixed(self.0 + o.0) } }
+/// # impl core::ops::Sub for Fixed { type Output = Self; fn sub(self, o: Self) -> Self { Fixed(self.0 - o.0) } }
+/// # impl core::ops::Mul for Fixed { type Output = Self; fn mul(self, o: Self) -> Self { Fixed(self.0 * o.0 >> 16) } }
+/// # impl core::ops::Div for Fixed { type Output = Self; fn div(self, o: Self) -> Self { Fixed((self.0 << 16) / o.0) } }
+/// # impl core::ops::Rem for Fixed { type Output = Self; fn rem(self, o: Self) -> Self { Fixed(self.0 % o.0) } }
+/// # impl num_traits::Zero for Fixed { fn zero() -> Self { Fixed(0) } fn is_zero(&self) -> bool { self.0 == 0 } }
+/// # impl num_traits::One for Fixed { fn one() -> Self { Fixed(1 << 16) } }
+/// # impl num_traits::Num for Fixed {
+/// #     type FromStrRadixErr = ();
+/// #     fn from_str_radix(_: &str, _: u32) -> Result<Self, ()> { Err(()) }
+/// # }
+/// # impl num_traits::FromPrimitive for Fixed {
+/// #     fn from_i64(n: i64) -> Option<Self> { Some(Fixed(n << 16)) }
+/// #     fn from_u64(n: u64) -> Option<Self> { Some(Fixed((n as i64) << 16)) }
+/// # }
+/// // a fixed point number with 16 fractional bits
+/// impl LinSpaceNum for Fixed {}
+///
+/// let it = iter_num_tools::lin_space(Fixed(0)..=Fixed(1 << 16), 5);
+/// assert!(it.map(|x| x.0).eq([0, 1 << 14, 1 << 15, 3 << 14, 1 << 16]));
+/// ```
+///
+/// Before 0.8, [`ToLinSpace`] was implemented for any `Num + FromPrimitive + Copy` type.
+/// Types from outside this crate now need to implement this trait
+pub trait LinSpaceNum: Num + FromPrimitive + Copy {
+    /// The distance from `start` to `end` if this is an integer type, or `None` otherwise
+    fn span(_start: Self, _end: Self) -> Option<Span> {
+        None
+    }
+
+    /// The value `by` away from `start`, in the direction of `span`.
+    /// Only called for types that return a [`Span`]
+    fn offset(start: Self, span: Span, by: u128) -> Self {
+        let by = Self::from_u128(by).expect("offset must fit in the item type");
+        match span.descending {
+            false => start + by,
+            true => start - by,
+        }
+    }
+}
+
+macro_rules! integer_lin_space_num {
+    ($($t:ty)*) => {$(
+        impl LinSpaceNum for $t {
+            // the distance is taken with wrapping arithmetic on the two's complement bits,
+            // which is exact since any two values of the type are less than 2^128 apart
+            fn span(start: Self, end: Self) -> Option<Span> {
+                let descending = start > end;
+                let (start, end) = (start as i128 as u128, end as i128 as u128);
+                let magnitude = match descending {
+                    false => end.wrapping_sub(start),
+                    true => start.wrapping_sub(end),
+                };
+                Some(Span { descending, magnitude })
+            }
+
+            fn offset(start: Self, span: Span, by: u128) -> Self {
+                let start = start as i128 as u128;
+                let value = match span.descending {
+                    false => start.wrapping_add(by),
+                    true => start.wrapping_sub(by),
+                };
+                value as Self
+            }
+        }
+    )*};
+}
+
+integer_lin_space_num!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+
+impl LinSpaceNum for f32 {}
+impl LinSpaceNum for f64 {}
+
 /// A helper trait for [`lin_space`]
 pub trait ToLinSpace {
     /// The item that this is a linear space over
     type Item;
     /// Create the lin space
     fn into_lin_space(self, step: usize) -> IntoLinSpace<Self::Item>;
+    /// Create the lin space, checking that the range and number of steps are valid
+    fn try_into_lin_space(self, steps: usize) -> Result<IntoLinSpace<Self::Item>, SpaceError>;
 }
 
-impl<T: Num + FromPrimitive + Copy> ToLinSpace for Range<T> {
+impl<T: LinSpaceNum> ToLinSpace for Range<T> {
     type Item = T;
 
     fn into_lin_space(self, steps: usize) -> IntoLinSpace<Self::Item> {
         let Range { start, end } = self;
-        let step = (end - start) / T::from_usize(steps).unwrap();
-        IntoLinSpace::new(steps, LinearInterpolation { start, step })
+        let interpolate = LinearInterpolation::between(start, end, steps)
+            .expect("the number of steps must fit in the item type");
+        IntoLinSpace::new(steps, interpolate)
+    }
+
+    fn try_into_lin_space(self, steps: usize) -> Result<IntoLinSpace<Self::Item>, SpaceError> {
+        let Range { start, end } = self;
+        if steps == 0 {
+            return Err(SpaceError::ZeroSteps);
+        }
+        let interpolate =
+            LinearInterpolation::between(start, end, steps).ok_or(SpaceError::CountOverflow)?;
+        if !is_finite(start) || !is_finite(end) || !is_finite(interpolate.step) {
+            return Err(SpaceError::NonFiniteBound);
+        }
+        Ok(IntoLinSpace::new(steps, interpolate))
     }
 }
 
-impl<T: Num + FromPrimitive + Copy> ToLinSpace for RangeInclusive<T> {
+impl<T: LinSpaceNum> ToLinSpace for RangeInclusive<T> {
     type Item = T;
 
     fn into_lin_space(self, steps: usize) -> IntoLinSpace<Self::Item> {
         let (start, end) = self.into_inner();
-        let step = (end - start) / T::from_usize(steps - 1).unwrap();
-        IntoLinSpace::new(steps, LinearInterpolation { start, step })
+        let interpolate = LinearInterpolation::between(start, end, steps.saturating_sub(1))
+            .expect("the number of steps must fit in the item type");
+        IntoLinSpace::new(steps, interpolate)
+    }
+
+    fn try_into_lin_space(self, steps: usize) -> Result<IntoLinSpace<Self::Item>, SpaceError> {
+        let (start, end) = self.into_inner();
+        if steps == 0 {
+            return Err(SpaceError::ZeroSteps);
+        }
+        let interpolate =
+            LinearInterpolation::between(start, end, steps - 1).ok_or(SpaceError::CountOverflow)?;
+        if !is_finite(start) || !is_finite(end) || !is_finite(interpolate.step) {
+            return Err(SpaceError::NonFiniteBound);
+        }
+        Ok(IntoLinSpace::new(steps, interpolate))
     }
 }
 
-impl<T: Num + FromPrimitive> Interpolate for LinearInterpolation<T> {
+impl<T: LinSpaceNum> LinearInterpolation<T> {
+    /// Steps from `start` to `end`, landing exactly on `end` after `end_index` steps.
+    /// Returns `None` if `end_index` does not fit in `T`
+    pub(crate) fn between(start: T, end: T, end_index: usize) -> Option<Self> {
+        // integer division rounds towards zero, so the steps fall short of `end`
+        // by some remainder that needs to be made up along the way. The distance is
+        // kept apart from `T` so that it cannot overflow, such as for `-100i8..=100` or `10u8..=0`
+        let (step, span) = match (end_index, T::span(start, end)) {
+            (0, _) => (T::zero(), None),
+            (_, Some(span)) => {
+                let step = span.magnitude / end_index as u128;
+                let step = match span.descending {
+                    false => T::from_u128(step),
+                    // negated as `-(step - 1) - 1`, so that a step of 2^127 still fits in an i128
+                    true => i128::try_from(step.wrapping_sub(1))
+                        .ok()
+                        .and_then(|step| T::from_i128(-step - 1)),
+                };
+                (step.unwrap_or_else(T::zero), Some(span))
+            }
+            (_, None) => ((end - start) / T::from_usize(end_index)?, None),
+        };
+
+        Some(LinearInterpolation {
+            start,
+            step,
+            end,
+            end_index: Some(end_index),
+            from_end: true,
+            span,
+        })
+    }
+
+    /// The integer value at `x`, carrying the remainder Bresenham style,
+    /// so the steps are spread evenly and reach exactly `end` at `end_index`
+    fn integer_value(self, span: Span, end_index: usize, x: usize) -> T {
+        let (n, x) = (end_index as u128, x as u128);
+        let (step, remainder) = (span.magnitude / n, span.magnitude % n);
+        // `remainder < end_index` and `x <= end_index`, so this cannot overflow
+        T::offset(self.start, span, step * x + remainder * x / n)
+    }
+
+    /// The index an inclusive arange puts `end` in place of, where the values
+    /// stop being an arithmetic series
+    fn substituted_end(self) -> Option<usize> {
+        match self.from_end {
+            false => self.end_index,
+            true => None,
+        }
+    }
+
+    /// The sum of the values at the indices in `range`, if it can be computed
+    /// without adding up every value
+    pub(crate) fn sum_range(self, range: Range<usize>) -> Option<T> {
+        if let Some(end_index) = self.substituted_end().filter(|i| range.contains(i)) {
+            return Some(self.sum_range(range.start..end_index)? + self.end);
+        }
+        // the remainder is spread unevenly, so the values are not quite an arithmetic series
+        if let (Some(end_index), Some(span)) = (self.end_index, self.span) {
+            if span.magnitude % end_index as u128 != 0 {
+                return None;
+            }
+        }
+        let n = range.len();
+        let (first, last) = match n {
+            0 => return Some(T::zero()),
+            1 => return Some(self.interpolate(range.start)),
+            _ => (
+                self.interpolate(range.start),
+                self.interpolate(range.end - 1),
+            ),
+        };
+        // an arithmetic series sums to n * (first + last) / 2. For an odd
+        // number of values `first + last` is even, so halve whichever is even
+        let two = T::one() + T::one();
+        match n % 2 {
+            0 => Some(T::from_usize(n / 2)? * (first + last)),
+            _ => Some(T::from_usize(n)? * ((first + last) / two)),
+        }
+    }
+}
+
+impl<T: LinSpaceNum> Interpolate for LinearInterpolation<T> {
     type Item = T;
     fn interpolate(self, x: usize) -> T {
-        let Self { start, step } = self;
-        start + T::from_usize(x).unwrap() * step
+        match (self.end_index, self.span) {
+            (Some(end_index), Some(span)) => self.integer_value(span, end_index, x),
+            // counting back from the end keeps the rounding error small near the end,
+            // and gives exactly `end` at `end_index`
+            (Some(end_index), None) if self.from_end && x > end_index / 2 => {
+                self.end - T::from_usize(end_index - x).unwrap() * self.step
+            }
+            (Some(end_index), None) if !self.from_end && x == end_index => self.end,
+            _ => self.start + T::from_usize(x).unwrap() * self.step,
+        }
+    }
+
+    fn fill(self, start: usize, out: &mut [T])
+    where
+        Self: Copy,
+    {
+        // split the buffer where `interpolate` would switch ends, so that
+        // neither loop has a branch in it
+        let mid = match self.end_index {
+            Some(_) if self.span.is_some() => {
+                for (x, out) in (start..).zip(out) {
+                    *out = self.interpolate(x);
+                }
+                return;
+            }
+            Some(end_index) if self.from_end => {
+                (end_index / 2 + 1).saturating_sub(start).min(out.len())
+            }
+            _ => out.len(),
+        };
+        let (front, back) = out.split_at_mut(mid);
+        for (x, out) in (start..).zip(front) {
+            *out = self.start + T::from_usize(x).unwrap() * self.step;
+        }
+        match self.end_index {
+            Some(end_index) if self.from_end => {
+                for (x, out) in (start + mid..).zip(back) {
+                    *out = self.end - T::from_usize(end_index - x).unwrap() * self.step;
+                }
+            }
+            Some(end_index) => {
+                if let Some(out) = end_index.checked_sub(start).and_then(|i| out.get_mut(i)) {
+                    *out = self.end;
+                }
+            }
+            None => {}
+        }
     }
 }
 
@@ -71,8 +365,167 @@ pub type LinSpace<T> = Space<LinearInterpolation<T>>;
 /// [`IntoIterator`] returned by [`ToLinSpace::into_lin_space`]
 pub type IntoLinSpace<T> = IntoSpace<LinearInterpolation<T>>;
 
+impl<T: Copy> LinSpace<T> {
+    /// The first value of the space, before any values were taken from the iterator
+    ///
+    /// ```
+    /// use iter_num_tools::{arange, lin_space};
+    ///
+    /// let it = lin_space(1.0..3.0, 4);
+    /// assert_eq!((it.start(), it.step(), it.end()), (1.0, 0.5, 3.0));
+    ///
+    /// let it = arange(0.0..1.0, 0.25);
+    /// assert_eq!((it.start(), it.step(), it.end()), (0.0, 0.25, 1.0));
+    /// ```
+    pub fn start(&self) -> T {
+        self.interpolate.start
+    }
+
+    /// The difference between consecutive values.
+    /// For integer spaces where the range does not divide evenly,
+    /// some values will be one further apart than this.
+    /// If the step does not fit in `T`, such as when counting down an unsigned range, this is zero
+    pub fn step(&self) -> T {
+        self.interpolate.step
+    }
+
+    /// The end of the range the space was created from.
+    /// This is only one of the values if the range was inclusive
+    pub fn end(&self) -> T {
+        self.interpolate.end
+    }
+}
+
+impl<T: LinSpaceNum + PartialOrd> LinSpace<T> {
+    /// The smallest value left in the iterator, found without iterating.
+    /// Returns `None` if the iterator is empty.
+    ///
+    /// This is not called `min`, since [`Iterator::min`] takes the iterator by value
+    /// and would be picked over a method taking `&self`
+    ///
+    /// ```
+    /// use iter_num_tools::lin_space;
+    ///
+    /// assert_eq!(lin_space(5.0..=-5.0, 11).min_value(), Some(-5.0));
+    /// assert_eq!(lin_space(0..10, 5).min_value(), Some(0));
+    /// ```
+    pub fn min_value(&self) -> Option<T> {
+        let (first, last) = (self.peek()?, self.peek_back()?);
+        Some(if first <= last { first } else { last })
+    }
+
+    /// The largest value left in the iterator, found without iterating.
+    /// Returns `None` if the iterator is empty
+    ///
+    /// ```
+    /// use iter_num_tools::lin_space;
+    ///
+    /// assert_eq!(lin_space(5.0..=-5.0, 11).max_value(), Some(5.0));
+    /// assert_eq!(lin_space(0..10, 5).max_value(), Some(8));
+    /// ```
+    pub fn max_value(&self) -> Option<T> {
+        let (first, last) = (self.peek()?, self.peek_back()?);
+        Some(if first <= last { last } else { first })
+    }
+
+    /// The sum of the values left in the iterator, computed as an arithmetic series
+    /// without iterating where possible
+    ///
+    /// ```
+    /// use iter_num_tools::lin_space;
+    ///
+    /// assert_eq!(lin_space(0.0..=1.0, 101).arithmetic_sum(), 50.5);
+    /// assert_eq!(lin_space(1..=100, 100).arithmetic_sum(), 5050);
+    /// ```
+    pub fn arithmetic_sum(&self) -> T {
+        let interpolate = self.interpolate;
+        interpolate
+            .sum_range(self.range.clone())
+            .unwrap_or_else(|| {
+                self.range
+                    .clone()
+                    .fold(T::zero(), |sum, x| sum + interpolate.interpolate(x))
+            })
+    }
+}
+
+impl<T: Real + LinSpaceNum> LinSpace<T> {
+    /// The mean of the values left in the iterator, computed without iterating.
+    /// Returns `None` if the iterator is empty.
+    ///
+    /// The sum is also computed without iterating, by [`LinSpace::arithmetic_sum`]
+    ///
+    /// ```
+    /// use iter_num_tools::lin_space;
+    ///
+    /// let it = lin_space(0.0..=1.0, 1_000_001);
+    /// assert_eq!(it.mean(), Some(0.5));
+    /// ```
+    pub fn mean(&self) -> Option<T> {
+        Some(self.moments()?.0)
+    }
+
+    /// The population variance of the values left in the iterator, computed without iterating.
+    /// Returns `None` if the iterator is empty
+    ///
+    /// ```
+    /// use iter_num_tools::lin_space;
+    ///
+    /// // 1, 2, 3, 4, 5
+    /// let it = lin_space(1.0..=5.0, 5);
+    /// assert_eq!(it.variance(), Some(2.0));
+    /// ```
+    pub fn variance(&self) -> Option<T> {
+        Some(self.moments()?.1)
+    }
+
+    /// The mean and population variance of the values left in the iterator
+    fn moments(&self) -> Option<(T, T)> {
+        let interpolate = self.interpolate;
+        let mut series = self.range.clone();
+        let end = match interpolate.substituted_end() {
+            Some(end_index) if series.contains(&end_index) => {
+                series.end = end_index;
+                Some(interpolate.end)
+            }
+            _ => None,
+        };
+
+        let n = series.len();
+        let moments = match n {
+            0 => None,
+            1 => Some((interpolate.interpolate(series.start), T::zero())),
+            _ => {
+                let first = interpolate.interpolate(series.start);
+                let last = interpolate.interpolate(series.end - 1);
+                // evenly spaced values `d` apart have a variance of d² (n² - 1) / 12
+                let d = (last - first) / T::from_usize(n - 1)?;
+                let n = T::from_usize(n)?;
+                let variance = d * d * (n * n - T::one()) / T::from_u8(12)?;
+                Some(((first + last) / (T::one() + T::one()), variance))
+            }
+        };
+
+        match (moments, end) {
+            (moments, None) => moments,
+            (None, Some(end)) => Some((end, T::zero())),
+            (Some((mean, variance)), Some(end)) => {
+                // add the substituted `end` to the series as one more value
+                let n = T::from_usize(n)?;
+                let total = n + T::one();
+                let merged = (n * mean + end) / total;
+                let spread = n * variance + n * (mean - merged).powi(2) + (end - merged).powi(2);
+                Some((merged, spread / total))
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::{advance, check_double_ended_iter};
+    use core::iter::Sum;
+
     use super::*;
 
     #[test]
@@ -126,6 +579,341 @@ mod tests {
         assert_eq!(lin_space(0.0..=5.0, 6).last(), Some(5.0));
     }
 
+    fn check_exact_endpoints<T>(start: T, end: T, steps: usize)
+    where
+        T: LinSpaceNum + PartialOrd + core::fmt::Debug,
+    {
+        let it = lin_space(start..=end, steps);
+        assert_eq!(it.len(), steps);
+        assert_eq!(it.clone().next(), Some(start));
+        assert_eq!(it.clone().last(), Some(end));
+
+        let ascending = start <= end;
+        let mut prev = start;
+        for x in it {
+            if ascending {
+                assert!(prev <= x, "{start:?}..={end:?} x{steps}: {prev:?} > {x:?}");
+            } else {
+                assert!(prev >= x, "{start:?}..={end:?} x{steps}: {prev:?} < {x:?}");
+            }
+            prev = x;
+        }
+    }
+
+    #[test]
+    fn test_lin_space_exact_endpoints() {
+        let bounds = [
+            (0.0, 1.0),
+            (0.1, 0.7),
+            (-3.3, 7.9),
+            (1e-10, 1e10),
+            (1e16, 1e16 + 64.0),
+            (1.0, 1.0 + 4.0 * f64::EPSILON),
+            (123.456, -654.321),
+        ];
+        for (start, end) in bounds {
+            for steps in [2, 3, 7, 10, 11, 100, 999, 1_000_000] {
+                check_exact_endpoints(start, end, steps);
+                check_exact_endpoints(start as f32, end as f32, steps);
+            }
+        }
+    }
+
+    #[test]
+    fn test_lin_space_integers() {
+        check_double_ended_iter(lin_space(0..=10, 4), [0, 3, 6, 10]);
+        check_double_ended_iter(lin_space(0..=20, 7), [0, 3, 6, 10, 13, 16, 20]);
+        check_double_ended_iter(lin_space(RangeInclusive::new(10, -10), 4), [10, 4, -3, -10]);
+        check_double_ended_iter(lin_space(0..10, 4), [0, 2, 5, 7]);
+        check_double_ended_iter(lin_space(0u8..=255, 4), [0, 85, 170, 255]);
+        check_double_ended_iter(lin_space(0i64..=i64::MAX, 3), [0, i64::MAX / 2, i64::MAX]);
+    }
+
+    #[test]
+    fn test_lin_space_integers_wide() {
+        // ranges wider than the type, or counting down an unsigned type
+        check_double_ended_iter(lin_space(-100i8..=100, 3), [-100, 0, 100]);
+        check_double_ended_iter(lin_space(-128i8..=127, 4), [-128, -43, 42, 127]);
+        check_double_ended_iter(lin_space(RangeInclusive::new(10u8, 0), 3), [10, 5, 0]);
+        check_double_ended_iter(
+            lin_space(RangeInclusive::new(255u8, 0), 4),
+            [255, 170, 85, 0],
+        );
+        check_double_ended_iter(
+            lin_space(
+                Range {
+                    start: 10u8,
+                    end: 0,
+                },
+                5,
+            ),
+            [10, 8, 6, 4, 2],
+        );
+        check_double_ended_iter(lin_space(i64::MIN..=i64::MAX, 3), [i64::MIN, -1, i64::MAX]);
+        // the distance is wider than an i128
+        check_double_ended_iter(
+            lin_space(0u128..=u128::MAX, 3),
+            [0, u128::MAX / 2, u128::MAX],
+        );
+        check_double_ended_iter(lin_space(i128::MIN..i128::MAX, 2), [i128::MIN, -1]);
+        check_double_ended_iter(
+            lin_space(
+                Range {
+                    start: u128::MAX,
+                    end: 0,
+                },
+                3,
+            ),
+            [u128::MAX, u128::MAX / 3 * 2, u128::MAX / 3],
+        );
+        let it = try_lin_space(RangeInclusive::new(i128::MAX, i128::MIN), 2).unwrap();
+        assert_eq!(it.step(), 0);
+        check_double_ended_iter(it, [i128::MAX, i128::MIN]);
+        assert_eq!(
+            lin_space(RangeInclusive::new(0i128, i128::MIN), 2).step(),
+            i128::MIN
+        );
+        assert!(lin_space(-128i8..=127, 256).eq(-128..=127));
+        assert!(lin_space(RangeInclusive::new(255u8, 0), 256).eq((0..=255).rev()));
+
+        let mut buf = [0u8; 3];
+        assert_eq!(
+            lin_space(RangeInclusive::new(10u8, 0), 3).fill_into(&mut buf),
+            3
+        );
+        assert_eq!(buf, [10, 5, 0]);
+        assert_eq!(lin_space(RangeInclusive::new(10u8, 0), 3).sum::<u8>(), 15);
+        assert_eq!(
+            lin_space(RangeInclusive::new(10u8, 0), 3).min_value(),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn test_lin_space_integers_even() {
+        // every gap is the same size, give or take one
+        for end in [1u8, 7, 100, 200, 254, 255] {
+            for steps in 2..=256 {
+                let it = lin_space(0u8..=end, steps);
+                assert_eq!(it.clone().last(), Some(end));
+
+                let step = end as usize / (steps - 1);
+                let values: Vec<u8> = it.collect();
+                for w in values.windows(2) {
+                    let gap = (w[1] - w[0]) as usize;
+                    assert!(
+                        gap == step || gap == step + 1,
+                        "0..={end} x{steps}: {values:?}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_lin_space_single_step() {
+        assert!(lin_space(1.0..=5.0, 1).eq(vec![1.0]));
+        assert!(lin_space(1..=5, 1).eq(vec![1]));
+        assert_eq!(lin_space(1.0..=5.0, 0).len(), 0);
+    }
+
+    #[test]
+    fn test_lin_space_fill_into() {
+        fn check<T: Copy + Default + PartialEq + core::fmt::Debug>(it: LinSpace<T>)
+        where
+            LinearInterpolation<T>: Interpolate<Item = T>,
+        {
+            let expected: Vec<T> = it.clone().collect();
+            for skip in [0, 1, expected.len() / 2, expected.len()] {
+                let mut it = advance(it.clone(), skip);
+                let mut buf = vec![T::default(); expected.len() + 1];
+                let n = it.fill_into(&mut buf);
+                assert_eq!(buf[..n], expected[skip..]);
+                assert_eq!(it.len(), 0);
+            }
+        }
+        check(lin_space(0.0..=1.0, 101));
+        check(lin_space(-3.0f32..5.0, 7));
+        check(lin_space(0..=10, 4));
+        check(lin_space(RangeInclusive::new(10, -10), 7));
+        check(lin_space(0..10, 5));
+    }
+
+    #[test]
+    fn test_lin_space_chunks_exact() {
+        let it = lin_space(-1.0f32..=3.0, 1003);
+        let expected: Vec<f32> = it.clone().collect();
+        let chunks = it.chunks_exact::<8>();
+        assert_eq!(chunks.len(), 125);
+        let values: Vec<f32> = chunks.clone().flatten().chain(chunks.remainder()).collect();
+        assert_eq!(values, expected);
+
+        let chunks = lin_space(RangeInclusive::new(10, -10), 7).chunks_exact::<2>();
+        check_double_ended_iter(chunks.clone(), [[10, 7], [4, 0], [-3, -6]]);
+        check_double_ended_iter(chunks.remainder(), [-10]);
+    }
+
+    #[test]
+    fn test_lin_space_sum() {
+        fn naive<T: Num + Copy>(it: impl Iterator<Item = T>) -> T {
+            it.fold(T::zero(), |a, b| a + b)
+        }
+
+        let it = lin_space(0.0..=1.0, 101);
+        assert!((it.clone().sum::<f64>() - 50.5).abs() < 1e-12);
+        assert!((it.arithmetic_sum() - 50.5).abs() < 1e-12);
+        assert!((it.arithmetic_sum() - naive(it.clone().rev())).abs() < 1e-12);
+
+        let it = lin_space(-3.0f32..5.0, 7);
+        assert!((it.clone().sum::<f32>() - naive(it.clone())).abs() < 1e-5);
+        assert!((it.arithmetic_sum() - naive(it)).abs() < 1e-5);
+
+        // integer sums are exact, with or without a remainder to spread
+        for (range, steps) in [
+            (0..=10, 11),
+            (-5..=7, 4),
+            (0..=10, 4),
+            (RangeInclusive::new(3, -8), 5),
+            (0..=0, 1),
+        ] {
+            let mut it = lin_space(range, steps);
+            assert_eq!(it.arithmetic_sum(), it.clone().sum::<i32>());
+            it.next();
+            assert_eq!(it.arithmetic_sum(), it.clone().sum::<i32>());
+            it.next_back();
+            assert_eq!(it.arithmetic_sum(), it.clone().sum::<i32>());
+        }
+        assert_eq!(lin_space(0u8..=100, 0).arithmetic_sum(), 0);
+        assert_eq!(lin_space(0u8..=100, 1).arithmetic_sum(), 0);
+        assert_eq!(lin_space(250u8..=250, 1).arithmetic_sum(), 250);
+        assert_eq!(lin_space(250u8..=250, 1).sum::<u8>(), 250);
+
+        // summing into another type still sees every value
+        struct Count(usize);
+        impl Sum<f64> for Count {
+            fn sum<I: Iterator<Item = f64>>(iter: I) -> Self {
+                Count(iter.count())
+            }
+        }
+        let Count(count) = lin_space(0.0..=1.0, 101).sum();
+        assert_eq!(count, 101);
+    }
+
+    #[test]
+    fn test_lin_space_statistics() {
+        fn check(it: LinSpace<f64>) {
+            let values: Vec<f64> = it.clone().collect();
+            let n = values.len() as f64;
+            let mean = values.iter().sum::<f64>() / n;
+            let variance = values.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+            let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+            let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+
+            assert!((it.mean().unwrap() - mean).abs() < 1e-12);
+            assert!((it.variance().unwrap() - variance).abs() < 1e-9);
+            assert_eq!(it.min_value(), Some(min));
+            assert_eq!(it.max_value(), Some(max));
+        }
+
+        check(lin_space(0.0..=1.0, 101));
+        check(lin_space(10.0..-3.0, 7));
+        check(lin_space(2.0..=2.0, 1));
+        let mut it = lin_space(-4.0..=6.0, 11);
+        it.next();
+        it.nth_back(2);
+        check(it);
+
+        // an inclusive arange puts `end` in place of its last value, off the series
+        let mut it = crate::arange(0.0..=1.1, (0.25, crate::Tolerance::Relative(0.5)));
+        assert!((it.arithmetic_sum() - 2.6).abs() < 1e-12);
+        assert!((it.mean().unwrap() - 0.52).abs() < 1e-12);
+        assert!((it.variance().unwrap() - 0.1466).abs() < 1e-12);
+        check(it.clone());
+        it.nth(3);
+        check(it);
+        let mut it = crate::arange(0.0..=1.1, (0.25, crate::Tolerance::Relative(0.5)));
+        it.next_back();
+        check(it);
+
+        let mut it = lin_space(0.0..=1.0, 2);
+        it.next();
+        it.next();
+        assert_eq!(it.mean(), None);
+        assert_eq!(it.variance(), None);
+        assert_eq!(it.min_value(), None);
+
+        assert_eq!(
+            lin_space(RangeInclusive::new(10, -10), 7).min_value(),
+            Some(-10)
+        );
+        assert_eq!(
+            lin_space(RangeInclusive::new(10, -10), 7).max_value(),
+            Some(10)
+        );
+    }
+
+    #[test]
+    fn test_lin_space_accessors() {
+        let mut it = lin_space(2.0..=-2.0, 5);
+        it.nth(1);
+        assert_eq!((it.start(), it.step(), it.end()), (2.0, -1.0, -2.0));
+        assert_eq!(it.remaining_range(), 2..5);
+
+        // integer steps are truncated, and the remainder spread out
+        let it = lin_space(0..=10, 4);
+        assert_eq!((it.start(), it.step(), it.end()), (0, 3, 10));
+    }
+
+    #[test]
+    fn test_try_lin_space() {
+        let it = try_lin_space(1.0..=5.0, 5).unwrap();
+        assert!(it.eq(vec![1.0, 2.0, 3.0, 4.0, 5.0]));
+
+        let it = try_lin_space(1.0..=5.0, 1).unwrap();
+        assert!(it.eq(vec![1.0]));
+
+        let it = try_lin_space(0..=8, 3).unwrap();
+        assert!(it.eq(vec![0, 4, 8]));
+
+        // the distance between the bounds does not fit in the type
+        let it = try_lin_space(RangeInclusive::new(10u8, 0), 3).unwrap();
+        assert!(it.eq(vec![10, 5, 0]));
+        let it = try_lin_space(-100i8..=100, 3).unwrap();
+        assert!(it.eq(vec![-100, 0, 100]));
+
+        // more steps than the integer type can count, which `lin_space` accepts too
+        let it = try_lin_space(0u8..10, 256).unwrap();
+        assert!(it.eq(lin_space(0u8..10, 256)));
+        let it = try_lin_space(0u8..=10, 300).unwrap();
+        assert!(it.eq(lin_space(0u8..=10, 300)));
+        let it = try_lin_space(0u128..=u128::MAX, 3).unwrap();
+        assert!(it.eq(lin_space(0u128..=u128::MAX, 3)));
+    }
+
+    #[test]
+    fn test_try_lin_space_errors() {
+        assert_eq!(
+            try_lin_space(0.0..1.0, 0).unwrap_err(),
+            SpaceError::ZeroSteps
+        );
+        assert_eq!(
+            try_lin_space(0.0..=1.0, 0).unwrap_err(),
+            SpaceError::ZeroSteps
+        );
+        assert_eq!(
+            try_lin_space(0.0..f64::INFINITY, 2).unwrap_err(),
+            SpaceError::NonFiniteBound
+        );
+        assert_eq!(
+            try_lin_space(f64::NAN..=1.0, 2).unwrap_err(),
+            SpaceError::NonFiniteBound
+        );
+        assert_eq!(
+            try_lin_space(-f64::MAX..=f64::MAX, 2).unwrap_err(),
+            SpaceError::NonFiniteBound
+        );
+    }
+
     #[test]
     #[cfg(feature = "iter_advance_by")]
     fn test_lin_space_advance_by() {
@@ -1,10 +1,15 @@
 use core::ops::{Range, RangeInclusive};
 use num_traits::{real::Real, FromPrimitive};
 
-use crate::space::{Interpolate, IntoSpace, Space};
+use crate::{
+    error::{is_finite, SpaceError},
+    space::{Interpolate, IntoSpace, Space},
+};
 
 /// Creates a logarithmic space over range with a fixed number of steps
 ///
+/// For inclusive ranges, the first and last values are exactly the bounds of the range.
+///
 /// ```
 /// use iter_num_tools::log_space;
 /// use itertools::zip_eq;
@@ -30,10 +35,99 @@ where
     range.into_log_space(steps).into_space()
 }
 
+/// Creates a logarithmic space over range with a fixed number of steps,
+/// returning an error instead of panicking if the space is invalid
+///
+/// ```
+/// use iter_num_tools::{try_log_space, SpaceError};
+///
+/// let it = try_log_space(1.0..=100.0, 3).unwrap();
+/// assert_eq!(it.len(), 3);
+///
+/// assert_eq!(try_log_space(0.0..=100.0, 3).unwrap_err(), SpaceError::NonPositiveLogBound);
+/// assert_eq!(try_log_space(1.0..=100.0, 0).unwrap_err(), SpaceError::ZeroSteps);
+/// ```
+pub fn try_log_space<R>(range: R, steps: usize) -> Result<LogSpace<R::Item>, SpaceError>
+where
+    R: ToLogSpace,
+{
+    range.try_into_log_space(steps).map(IntoSpace::into_space)
+}
+
+/// Creates a logarithmic space over a range of exponents with a fixed number of steps,
+/// so that the values go from `base^start` to `base^end`
+///
+/// ```
+/// use iter_num_tools::log_space_base;
+/// use itertools::zip_eq;
+///
+/// // 4 decades, from 10^0 up to and including 10^3
+/// let it = log_space_base(0.0..=3.0, 4, 10.0);
+/// let expected: Vec<f64> = vec![1.0, 10.0, 100.0, 1000.0];
+/// assert!(zip_eq(it, expected).all(|(x, y)| (x-y).abs() < 1e-10));
+///
+/// // octaves, from 2^0 up to 2^4
+/// let it = log_space_base(0.0..4.0, 4, 2.0);
+/// let expected: Vec<f64> = vec![1.0, 2.0, 4.0, 8.0];
+/// assert!(zip_eq(it, expected).all(|(x, y)| (x-y).abs() < 1e-10));
+/// ```
+///
+/// The base is not checked, so a base that is not positive gives NaNs and a base of one
+/// gives only ones. See [`try_log_space_base`] to reject these instead.
+pub fn log_space_base<R>(range: R, steps: usize, base: R::Item) -> LogSpace<R::Item>
+where
+    R: ToLogSpaceBase,
+{
+    range.into_log_space_base(steps, base).into_space()
+}
+
+/// Creates a logarithmic space over a range of exponents with a fixed number of steps,
+/// returning an error instead of producing invalid values if the base or space is invalid
+///
+/// ```
+/// use iter_num_tools::{try_log_space_base, SpaceError};
+///
+/// let it = try_log_space_base(0.0..=2.0, 3, 10.0).unwrap();
+/// assert_eq!(it.len(), 3);
+///
+/// assert_eq!(try_log_space_base(0.0..=3.0, 4, -10.0).unwrap_err(), SpaceError::InvalidBase);
+/// assert_eq!(try_log_space_base(0.0..=3.0, 4, 1.0).unwrap_err(), SpaceError::InvalidBase);
+/// assert_eq!(try_log_space_base(0.0..=3.0, 0, 2.0).unwrap_err(), SpaceError::ZeroSteps);
+/// ```
+pub fn try_log_space_base<R>(
+    range: R,
+    steps: usize,
+    base: R::Item,
+) -> Result<LogSpace<R::Item>, SpaceError>
+where
+    R: ToLogSpaceBase,
+{
+    range
+        .try_into_log_space_base(steps, base)
+        .map(IntoSpace::into_space)
+}
+
 #[derive(Clone, Copy, Debug)]
 pub struct LogarithmicInterpolation<T> {
     pub start: T,
-    pub step: T,
+    /// The natural log of the ratio between consecutive values
+    pub ln_step: T,
+    pub end: T,
+    /// The index that `end` lands on, if it lands exactly on a step.
+    /// Values past the midpoint are then measured back from `end`, so that both ends are exact
+    pub end_index: Option<usize>,
+}
+
+impl<T> LogarithmicInterpolation<T> {
+    /// Steps from `start` to `end`, landing exactly on `end` after `end_index` steps
+    pub(crate) fn between(start: T, end: T, ln_step: T, end_index: usize) -> Self {
+        LogarithmicInterpolation {
+            start,
+            ln_step,
+            end,
+            end_index: Some(end_index),
+        }
+    }
 }
 
 /// A helper trait for [`log_space`]
@@ -42,13 +136,104 @@ pub trait ToLogSpace {
     type Item;
     /// Create the log space
     fn into_log_space(self, step: usize) -> IntoLogSpace<Self::Item>;
+    /// Create the log space, checking that the range and number of steps are valid
+    fn try_into_log_space(self, steps: usize) -> Result<IntoLogSpace<Self::Item>, SpaceError>;
 }
 
-impl<T: Real> Interpolate for LogarithmicInterpolation<T> {
+/// A helper trait for [`log_space_base`]
+pub trait ToLogSpaceBase {
+    /// The item that this is a range of exponents over
+    type Item;
+    /// Create the log space, treating the range as exponents of `base`
+    fn into_log_space_base(self, steps: usize, base: Self::Item) -> IntoLogSpace<Self::Item>;
+    /// Create the log space, checking that the base, range and number of steps are valid
+    fn try_into_log_space_base(
+        self,
+        steps: usize,
+        base: Self::Item,
+    ) -> Result<IntoLogSpace<Self::Item>, SpaceError>;
+}
+
+fn check_log_bounds<T: Real>(start: T, end: T) -> Result<(), SpaceError> {
+    if !is_finite(start) || !is_finite(end) {
+        Err(SpaceError::NonFiniteBound)
+    } else if start <= T::zero() || end <= T::zero() {
+        Err(SpaceError::NonPositiveLogBound)
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks the base and exponents, returning the bounds of the space they give
+fn check_log_base<T: Real>(start: T, end: T, base: T) -> Result<(T, T), SpaceError> {
+    if !is_finite(base) || base <= T::zero() || base == T::one() {
+        return Err(SpaceError::InvalidBase);
+    }
+    let (start, end) = (base.powf(start), base.powf(end));
+    if !is_finite(start) || !is_finite(end) {
+        return Err(SpaceError::NonFiniteBound);
+    }
+    Ok((start, end))
+}
+
+impl<T: Real + FromPrimitive> LogarithmicInterpolation<T> {
+    /// The product of the values at the indices in `range`, if it can be computed
+    /// without multiplying every value
+    fn product_range(self, range: Range<usize>) -> Option<T> {
+        let n = range.len();
+        let (first, last) = match n {
+            0 => return Some(T::one()),
+            1 => return Some(self.interpolate(range.start)),
+            _ => (
+                self.interpolate(range.start),
+                self.interpolate(range.end - 1),
+            ),
+        };
+        // the values are a geometric series, so their product is the
+        // geometric mean of the ends to the power of n
+        let ln_mean = (first.abs().ln() + last.abs().ln()) / (T::one() + T::one());
+        let product = (ln_mean * T::from_usize(n)?).exp();
+        // over a negative range every value is negative, so the product is too when n is odd
+        if first.is_sign_negative() && n % 2 == 1 {
+            Some(-product)
+        } else {
+            Some(product)
+        }
+    }
+}
+
+impl<T: Real + FromPrimitive> Interpolate for LogarithmicInterpolation<T> {
     type Item = T;
     fn interpolate(self, x: usize) -> T {
-        let Self { start, step } = self;
-        start * step.powi(x as i32)
+        // each value is computed directly from the nearest end, rather than by repeated
+        // multiplication, so the error does not grow with the number of steps
+        match self.end_index {
+            Some(end_index) if x > end_index / 2 => {
+                let n = T::from_usize(end_index - x).unwrap();
+                self.end * (-n * self.ln_step).exp()
+            }
+            _ => self.start * (T::from_usize(x).unwrap() * self.ln_step).exp(),
+        }
+    }
+
+    fn fill(self, start: usize, out: &mut [T])
+    where
+        Self: Copy,
+    {
+        let mid = match self.end_index {
+            Some(end_index) => (end_index / 2 + 1).saturating_sub(start).min(out.len()),
+            None => out.len(),
+        };
+        let (front, back) = out.split_at_mut(mid);
+        for (x, out) in (start..).zip(front) {
+            *out = self.start * (T::from_usize(x).unwrap() * self.ln_step).exp();
+        }
+        if let Some(end_index) = self.end_index {
+            for (x, out) in (start + mid..).zip(back) {
+                let n = T::from_usize(end_index - x).unwrap();
+                *out = self.end * (-n * self.ln_step).exp();
+            }
+        }
     }
 }
 
@@ -57,8 +242,25 @@ impl<T: Real + FromPrimitive> ToLogSpace for Range<T> {
 
     fn into_log_space(self, steps: usize) -> IntoLogSpace<Self::Item> {
         let Range { start, end } = self;
-        let step = (end / start).powf(T::from_usize(steps).unwrap().recip());
-        IntoLogSpace::new(steps, LogarithmicInterpolation { start, step })
+        let ln_step = (end.ln() - start.ln()) / T::from_usize(steps).unwrap();
+        IntoLogSpace::new(
+            steps,
+            LogarithmicInterpolation::between(start, end, ln_step, steps),
+        )
+    }
+
+    fn try_into_log_space(self, steps: usize) -> Result<IntoLogSpace<Self::Item>, SpaceError> {
+        let Range { start, end } = self;
+        if steps == 0 {
+            return Err(SpaceError::ZeroSteps);
+        }
+        check_log_bounds(start, end)?;
+        let len = T::from_usize(steps).ok_or(SpaceError::CountOverflow)?;
+        let ln_step = (end.ln() - start.ln()) / len;
+        Ok(IntoLogSpace::new(
+            steps,
+            LogarithmicInterpolation::between(start, end, ln_step, steps),
+        ))
     }
 }
 
@@ -67,8 +269,101 @@ impl<T: Real + FromPrimitive> ToLogSpace for RangeInclusive<T> {
 
     fn into_log_space(self, steps: usize) -> IntoLogSpace<Self::Item> {
         let (start, end) = self.into_inner();
-        let step = (end / start).powf(T::from_usize(steps - 1).unwrap().recip());
-        IntoLogSpace::new(steps, LogarithmicInterpolation { start, step })
+        let ln_step = match steps {
+            0 | 1 => T::zero(),
+            _ => (end.ln() - start.ln()) / T::from_usize(steps - 1).unwrap(),
+        };
+        let end_index = steps.saturating_sub(1);
+        IntoLogSpace::new(
+            steps,
+            LogarithmicInterpolation::between(start, end, ln_step, end_index),
+        )
+    }
+
+    fn try_into_log_space(self, steps: usize) -> Result<IntoLogSpace<Self::Item>, SpaceError> {
+        let (start, end) = self.into_inner();
+        check_log_bounds(start, end)?;
+        let ln_step = match steps {
+            0 => return Err(SpaceError::ZeroSteps),
+            1 => T::zero(),
+            _ => {
+                let len = T::from_usize(steps - 1).ok_or(SpaceError::CountOverflow)?;
+                (end.ln() - start.ln()) / len
+            }
+        };
+        Ok(IntoLogSpace::new(
+            steps,
+            LogarithmicInterpolation::between(start, end, ln_step, steps - 1),
+        ))
+    }
+}
+
+impl<T: Real + FromPrimitive> ToLogSpaceBase for Range<T> {
+    type Item = T;
+
+    fn into_log_space_base(self, steps: usize, base: T) -> IntoLogSpace<Self::Item> {
+        let Range { start, end } = self;
+        let ln_step = (end - start) * base.ln() / T::from_usize(steps).unwrap();
+        IntoLogSpace::new(
+            steps,
+            LogarithmicInterpolation::between(base.powf(start), base.powf(end), ln_step, steps),
+        )
+    }
+
+    fn try_into_log_space_base(
+        self,
+        steps: usize,
+        base: T,
+    ) -> Result<IntoLogSpace<Self::Item>, SpaceError> {
+        let Range { start, end } = self;
+        if steps == 0 {
+            return Err(SpaceError::ZeroSteps);
+        }
+        let (first, last) = check_log_base(start, end, base)?;
+        let len = T::from_usize(steps).ok_or(SpaceError::CountOverflow)?;
+        let ln_step = (end - start) * base.ln() / len;
+        Ok(IntoLogSpace::new(
+            steps,
+            LogarithmicInterpolation::between(first, last, ln_step, steps),
+        ))
+    }
+}
+
+impl<T: Real + FromPrimitive> ToLogSpaceBase for RangeInclusive<T> {
+    type Item = T;
+
+    fn into_log_space_base(self, steps: usize, base: T) -> IntoLogSpace<Self::Item> {
+        let (start, end) = self.into_inner();
+        let ln_step = match steps {
+            0 | 1 => T::zero(),
+            _ => (end - start) * base.ln() / T::from_usize(steps - 1).unwrap(),
+        };
+        let end_index = steps.saturating_sub(1);
+        IntoLogSpace::new(
+            steps,
+            LogarithmicInterpolation::between(base.powf(start), base.powf(end), ln_step, end_index),
+        )
+    }
+
+    fn try_into_log_space_base(
+        self,
+        steps: usize,
+        base: T,
+    ) -> Result<IntoLogSpace<Self::Item>, SpaceError> {
+        let (start, end) = self.into_inner();
+        let (first, last) = check_log_base(start, end, base)?;
+        let ln_step = match steps {
+            0 => return Err(SpaceError::ZeroSteps),
+            1 => T::zero(),
+            _ => {
+                let len = T::from_usize(steps - 1).ok_or(SpaceError::CountOverflow)?;
+                (end - start) * base.ln() / len
+            }
+        };
+        Ok(IntoLogSpace::new(
+            steps,
+            LogarithmicInterpolation::between(first, last, ln_step, steps - 1),
+        ))
     }
 }
 
@@ -77,8 +372,83 @@ pub type LogSpace<T> = Space<LogarithmicInterpolation<T>>;
 /// [`IntoIterator`] returned by [`ToLogSpace::into_log_space`]
 pub type IntoLogSpace<T> = IntoSpace<LogarithmicInterpolation<T>>;
 
+impl<T: Real> LogSpace<T> {
+    /// The first value of the space, before any values were taken from the iterator
+    ///
+    /// ```
+    /// use iter_num_tools::log_space;
+    ///
+    /// let it = log_space(1.0f64..=1000.0, 4);
+    /// assert_eq!((it.start(), it.end()), (1.0, 1000.0));
+    /// assert!((it.ratio() - 10.0).abs() < 1e-12);
+    /// ```
+    pub fn start(&self) -> T {
+        self.interpolate.start
+    }
+
+    /// The ratio between consecutive values
+    pub fn ratio(&self) -> T {
+        self.interpolate.ln_step.exp()
+    }
+
+    /// The end of the range the space was created from.
+    /// This is only one of the values if the range was inclusive
+    pub fn end(&self) -> T {
+        self.interpolate.end
+    }
+}
+
+impl<T: Real + FromPrimitive> LogSpace<T> {
+    /// The geometric mean of the values left in the iterator, computed without iterating.
+    /// Returns `None` if the iterator is empty.
+    ///
+    /// For a [`geom_space`](crate::geom_space) over a negative range,
+    /// this is the geometric mean of the magnitudes, negated.
+    ///
+    /// ```
+    /// use iter_num_tools::log_space;
+    ///
+    /// let it = log_space(1.0f64..=10_000.0, 1_000_001);
+    /// assert!((it.geometric_mean().unwrap() - 100.0).abs() < 1e-10);
+    /// ```
+    pub fn geometric_mean(&self) -> Option<T> {
+        let (first, last) = (self.peek()?, self.peek_back()?);
+        // the logs of a geometric series are an arithmetic series, so the mean
+        // of the logs is the mean of the first and last
+        let ln_mean = (first.abs().ln() + last.abs().ln()) / (T::one() + T::one());
+        let mean = ln_mean.exp();
+        Some(if first.is_sign_negative() {
+            -mean
+        } else {
+            mean
+        })
+    }
+
+    /// The product of the values left in the iterator, computed from the geometric mean
+    /// without iterating
+    ///
+    /// ```
+    /// use iter_num_tools::log_space;
+    ///
+    /// let it = log_space(1.0f64..=1000.0, 4);
+    /// assert!((it.geometric_product() - 1e6).abs() < 1e-6);
+    /// ```
+    pub fn geometric_product(&self) -> T {
+        let interpolate = self.interpolate;
+        interpolate
+            .product_range(self.range.clone())
+            .unwrap_or_else(|| {
+                self.range
+                    .clone()
+                    .fold(T::one(), |product, x| product * interpolate.interpolate(x))
+            })
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::advance;
+
     use super::*;
 
     use itertools::zip_eq;
@@ -107,6 +477,225 @@ mod tests {
         assert!(zip_eq(it.rev(), vec![100.0, 10.0, 1.0]).all(|(a, b)| (a - b).abs() < 1e-10))
     }
 
+    #[test]
+    fn test_log_space_exact_endpoints() {
+        let bounds = [(1.0, 1000.0), (0.3, 7.0), (1e-300, 1e300), (5.0, 0.002)];
+        for (start, end) in bounds {
+            for steps in [2, 3, 10, 101, 1_000_000] {
+                let it = log_space(start..=end, steps);
+                assert_eq!(it.clone().next(), Some(start));
+                assert_eq!(it.clone().last(), Some(end));
+                let ordered = if start < end {
+                    it.clone().zip(it.skip(1)).all(|(a, b)| a <= b)
+                } else {
+                    it.clone().zip(it.skip(1)).all(|(a, b)| a >= b)
+                };
+                assert!(ordered, "{start}..={end} x{steps} is not monotonic");
+            }
+        }
+    }
+
+    #[test]
+    fn test_log_space_large_steps_f64() {
+        // every 200_000th value is an exact power of 10
+        let it = log_space(1.0..=1e6, 1_200_001);
+        for (i, x) in it.enumerate().step_by(200_000) {
+            let expected = 10f64.powi(i as i32 / 200_000);
+            let err = ((x - expected) / expected).abs();
+            assert!(err < 1e-14, "value {i} was {x}, expected {expected}");
+        }
+    }
+
+    #[test]
+    fn test_log_space_large_steps_f32() {
+        // compare against the same space computed in f64
+        let steps = 2_000_000;
+        let it = log_space(0.5f32..=2e6, steps);
+        let ln_step = (2e6f64 / 0.5).ln() / (steps - 1) as f64;
+        let mut max_err = 0.0f64;
+        for (i, x) in it.enumerate() {
+            let expected = 0.5 * (i as f64 * ln_step).exp();
+            max_err = max_err.max(((x as f64 - expected) / expected).abs());
+        }
+        assert!(max_err < 8.0 * f32::EPSILON as f64, "max error {max_err}");
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn test_log_space_beyond_i32() {
+        let steps = 3_000_000_001;
+        let mut it = log_space(1.0..=2.0, steps);
+        let mid = it.clone().nth(1_500_000_000).unwrap();
+        assert!((mid - 2f64.sqrt()).abs() < 1e-14);
+        assert_eq!(it.nth(3_000_000_000), Some(2.0));
+    }
+
+    #[test]
+    fn test_log_space_base() {
+        let it = log_space_base(0.0..=3.0, 4, 10.0);
+        assert!(
+            zip_eq(it.clone(), vec![1.0, 10.0, 100.0, 1000.0]).all(|(a, b)| (a - b).abs() < 1e-10)
+        );
+        assert!(
+            zip_eq(it.rev(), vec![1000.0, 100.0, 10.0, 1.0]).all(|(a, b)| (a - b).abs() < 1e-10)
+        );
+
+        let it = log_space_base(-2.0..2.0, 4, 10.0);
+        assert!(zip_eq(it, vec![0.01, 0.1, 1.0, 10.0]).all(|(a, b)| (a - b).abs() < 1e-10));
+
+        let it = log_space_base(0.0..=1.0, 3, core::f64::consts::E);
+        let expected = [1.0, core::f64::consts::E.sqrt(), core::f64::consts::E];
+        assert!(zip_eq(it, expected).all(|(a, b)| (a - b).abs() < 1e-10));
+    }
+
+    #[test]
+    fn test_log_space_base_exact_powers() {
+        // the ends are exact powers of the base
+        let it = log_space_base(0.0..=10.0, 11, 2.0);
+        assert_eq!(it.len(), 11);
+        assert_eq!(it.clone().next(), Some(1.0));
+        assert_eq!(it.clone().last(), Some(1024.0));
+        assert!(
+            zip_eq(it, (0..=10).map(|i| (1 << i) as f64)).all(|(a, b)| ((a - b) / b).abs() < 1e-14)
+        );
+
+        let it = log_space_base(3.0f32..=-3.0, 7, 10.0);
+        assert_eq!(it.clone().next(), Some(1000.0));
+        assert_eq!(it.last(), Some(0.001));
+    }
+
+    #[test]
+    fn test_log_space_fill_into() {
+        for it in [log_space(1.0..=1e6, 101), log_space(1e-3..1e3, 10)] {
+            let expected: Vec<f64> = it.clone().collect();
+            for skip in [0, 1, 50] {
+                let mut it = advance(it.clone(), skip);
+                let mut buf = [0.0; 128];
+                let n = it.fill_into(&mut buf);
+                assert_eq!(buf[..n], expected[skip.min(expected.len())..]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_log_space_product() {
+        let rel = |a: f64, b: f64| ((a - b) / b).abs();
+
+        let it = log_space(1.0..=1000.0, 4);
+        assert!(rel(it.clone().product::<f64>(), 1e6) < 1e-12);
+        assert!(rel(it.geometric_product(), 1e6) < 1e-12);
+
+        let mut it = log_space(0.5..=8.0, 5);
+        it.next();
+        assert!(rel(it.geometric_product(), 64.0) < 1e-12);
+        assert!(rel(it.geometric_product(), it.clone().product::<f64>()) < 1e-12);
+
+        let it = crate::geom_space(-1.0..=-1000.0, 4);
+        assert!(rel(it.geometric_product(), 1e6) < 1e-12);
+        let it = crate::geom_space(-1.0..=-100.0, 3);
+        assert!(rel(it.geometric_product(), -1e3) < 1e-12);
+
+        assert_eq!(log_space(2.0..=4.0, 1).product::<f64>(), 2.0);
+        assert_eq!(log_space(2.0..=4.0, 1).geometric_product(), 2.0);
+        assert_eq!(log_space(2.0..=4.0, 0).product::<f64>(), 1.0);
+        assert_eq!(log_space(2.0..=4.0, 0).geometric_product(), 1.0);
+    }
+
+    #[test]
+    fn test_log_space_geometric_mean() {
+        let rel = |a: f64, b: f64| ((a - b) / b).abs();
+
+        assert!(
+            rel(
+                log_space(1.0..=1000.0, 4).geometric_mean().unwrap(),
+                31.622776601683793
+            ) < 1e-12
+        );
+        assert!(rel(log_space(2.0..=2.0, 1).geometric_mean().unwrap(), 2.0) < 1e-15);
+
+        let mut it = log_space(1.0..1e6, 6);
+        it.next();
+        it.next_back();
+        let values: Vec<f64> = it.clone().collect();
+        let expected = (values.iter().map(|x| x.ln()).sum::<f64>() / values.len() as f64).exp();
+        assert!(rel(it.geometric_mean().unwrap(), expected) < 1e-12);
+
+        let it = crate::geom_space(-1.0..=-100.0, 3);
+        assert!(rel(it.geometric_mean().unwrap(), -10.0) < 1e-12);
+
+        let mut it = log_space(1.0..=2.0, 1);
+        it.next();
+        assert_eq!(it.geometric_mean(), None);
+    }
+
+    #[test]
+    fn test_try_log_space() {
+        let it = try_log_space(1.0..=1000.0, 4).unwrap();
+        assert!(zip_eq(it, vec![1.0, 10.0, 100.0, 1000.0]).all(|(a, b)| (a - b).abs() < 1e-10));
+
+        let it = try_log_space(2.0..=1000.0, 1).unwrap();
+        assert!(it.eq(vec![2.0]));
+    }
+
+    #[test]
+    fn test_try_log_space_errors() {
+        assert_eq!(
+            try_log_space(1.0..10.0, 0).unwrap_err(),
+            SpaceError::ZeroSteps
+        );
+        assert_eq!(
+            try_log_space(1.0..=10.0, 0).unwrap_err(),
+            SpaceError::ZeroSteps
+        );
+        assert_eq!(
+            try_log_space(0.0..10.0, 2).unwrap_err(),
+            SpaceError::NonPositiveLogBound
+        );
+        assert_eq!(
+            try_log_space(1.0..=-10.0, 2).unwrap_err(),
+            SpaceError::NonPositiveLogBound
+        );
+        assert_eq!(
+            try_log_space(1.0..f64::INFINITY, 2).unwrap_err(),
+            SpaceError::NonFiniteBound
+        );
+        assert_eq!(
+            try_log_space(f64::NAN..=1.0, 2).unwrap_err(),
+            SpaceError::NonFiniteBound
+        );
+    }
+
+    #[test]
+    fn test_try_log_space_base() {
+        let it = try_log_space_base(0.0..=3.0, 4, 10.0).unwrap();
+        assert!(zip_eq(it, vec![1.0, 10.0, 100.0, 1000.0]).all(|(a, b)| (a - b).abs() < 1e-10));
+        let it = try_log_space_base(0.0..4.0, 4, 2.0).unwrap();
+        assert!(zip_eq(it, vec![1.0, 2.0, 4.0, 8.0]).all(|(a, b)| (a - b).abs() < 1e-10));
+
+        for base in [-10.0, 0.0, 1.0, f64::INFINITY, f64::NAN] {
+            assert_eq!(
+                try_log_space_base(0.0..=3.0, 4, base).unwrap_err(),
+                SpaceError::InvalidBase
+            );
+            assert_eq!(
+                try_log_space_base(0.0..3.0, 4, base).unwrap_err(),
+                SpaceError::InvalidBase
+            );
+        }
+        assert_eq!(
+            try_log_space_base(0.0..3.0, 0, 2.0).unwrap_err(),
+            SpaceError::ZeroSteps
+        );
+        assert_eq!(
+            try_log_space_base(0.0..=f64::NAN, 4, 2.0).unwrap_err(),
+            SpaceError::NonFiniteBound
+        );
+        assert_eq!(
+            try_log_space_base(0.0..=400.0, 4, 10.0).unwrap_err(),
+            SpaceError::NonFiniteBound
+        );
+    }
+
     #[test]
     fn test_log_space_exclusive_len() {
         let mut it = log_space(1.0..=1000.0, 4);
@@ -0,0 +1,289 @@
+use crate::space::Space;
+
+/// Keeps the per-axis indices of the last values taken from the front and back of a grid,
+/// so that stepping to the next value only needs to count through the axes
+/// instead of dividing the flat index again
+#[derive(Clone, Copy, Debug)]
+pub struct Odometer<const N: usize> {
+    /// The axes from the fastest varying to the slowest
+    axes: [usize; N],
+    /// Whether each pass over an axis goes the opposite way to the last
+    serpentine: bool,
+    /// The indices are kept as they would be without `serpentine`
+    front: Option<(usize, [usize; N])>,
+    back: Option<(usize, [usize; N])>,
+}
+
+impl<const N: usize> Odometer<N> {
+    pub(crate) fn new() -> Self {
+        Odometer {
+            axes: core::array::from_fn(|i| i),
+            serpentine: false,
+            front: None,
+            back: None,
+        }
+    }
+
+    pub(crate) fn with_axes(self, axes: [usize; N]) -> Self {
+        Odometer {
+            axes,
+            front: None,
+            back: None,
+            ..self
+        }
+    }
+
+    pub(crate) fn with_serpentine(self, serpentine: bool) -> Self {
+        Odometer {
+            serpentine,
+            front: None,
+            back: None,
+            ..self
+        }
+    }
+
+    /// Whether the pass over an axis after `passes` others goes backwards
+    #[inline]
+    pub(crate) fn is_backwards(&self, passes: usize) -> bool {
+        self.serpentine && passes % 2 == 1
+    }
+
+    /// The index along an axis of `len` values, where `q` is the flat index divided by the axis' stride
+    #[inline]
+    pub(crate) fn axis_index(&self, q: usize, len: usize) -> usize {
+        let z = q % len;
+        if self.is_backwards(q / len) {
+            len - 1 - z
+        } else {
+            z
+        }
+    }
+
+    /// Turns indices counted as if every pass over an axis goes the same way
+    /// into the indices in serpentine order
+    #[inline]
+    fn reflect(&self, mut index: [usize; N], lens: [usize; N]) -> [usize; N] {
+        if !self.serpentine {
+            return index;
+        }
+        // whether the number of passes done over the axis is odd,
+        // which is always even for the slowest axis since it only has the one pass
+        let mut odd = false;
+        for &axis in self.axes.iter().rev() {
+            let z = index[axis];
+            if odd {
+                index[axis] = lens[axis] - 1 - z;
+            }
+            odd = (z % 2 == 1) ^ (odd && lens[axis] % 2 == 1);
+        }
+        index
+    }
+
+    /// How far apart in the flat index consecutive values along each axis are
+    pub(crate) fn strides(&self, lens: [usize; N]) -> [usize; N] {
+        let mut strides = [0; N];
+        let mut stride = 1;
+        for &axis in &self.axes {
+            strides[axis] = stride;
+            stride *= lens[axis];
+        }
+        strides
+    }
+
+    /// Splits a flat index into an index along each axis
+    #[inline]
+    pub(crate) fn split(&self, x: usize, lens: [usize; N]) -> [usize; N] {
+        self.reflect(self.split_unreflected(x, lens), lens)
+    }
+
+    #[inline]
+    fn split_unreflected(&self, mut x: usize, lens: [usize; N]) -> [usize; N] {
+        let mut index = [0; N];
+        for &axis in &self.axes {
+            index[axis] = x % lens[axis];
+            x /= lens[axis];
+        }
+        index
+    }
+
+    /// The per-axis indices of `x`, counting up from the previous call if it was for `x - 1`
+    #[inline]
+    pub(crate) fn next(&mut self, x: usize, lens: [usize; N]) -> [usize; N] {
+        let index = match self.front {
+            Some((prev, mut index)) if prev + 1 == x => {
+                for &axis in &self.axes {
+                    index[axis] += 1;
+                    if index[axis] < lens[axis] {
+                        break;
+                    }
+                    index[axis] = 0;
+                }
+                index
+            }
+            _ => self.split_unreflected(x, lens),
+        };
+        self.front = Some((x, index));
+        self.reflect(index, lens)
+    }
+
+    /// The per-axis indices of `x`, counting down from the previous call if it was for `x + 1`
+    #[inline]
+    pub(crate) fn next_back(&mut self, x: usize, lens: [usize; N]) -> [usize; N] {
+        let index = match self.back {
+            Some((prev, mut index)) if x + 1 == prev => {
+                for &axis in &self.axes {
+                    if index[axis] > 0 {
+                        index[axis] -= 1;
+                        break;
+                    }
+                    index[axis] = lens[axis] - 1;
+                }
+                index
+            }
+            _ => self.split_unreflected(x, lens),
+        };
+        self.back = Some((x, index));
+        self.reflect(index, lens)
+    }
+}
+
+/// The interpolation behind a grid, which maps flat indices to points through an [`Odometer`].
+/// Lets [`GridSpace`](crate::GridSpace) and [`GridStep`](crate::GridStep) share the methods
+/// that change the order of the points
+pub trait GridInterpolation<const N: usize> {
+    /// The number of values along each axis
+    fn lens(&self) -> [usize; N];
+    /// The odometer that splits flat indices into indices along each axis
+    fn odometer_mut(&mut self) -> &mut Odometer<N>;
+
+    /// The same grid in the default order, where the first axis varies fastest through the flat indices.
+    /// The traversals of a grid that are not a [`Space`] are all built on this
+    fn unordered(mut self) -> Self
+    where
+        Self: Sized,
+    {
+        *self.odometer_mut() = Odometer::new();
+        self
+    }
+}
+
+impl<I> Space<I> {
+    /// Changing the order after values were taken would map the remaining indices to different points,
+    /// repeating some and skipping others
+    pub(crate) fn assert_unstarted<const N: usize>(&self, what: &str)
+    where
+        I: GridInterpolation<N>,
+    {
+        let len: usize = self.interpolate.lens().iter().product();
+        assert!(
+            self.range == (0..len),
+            "{what} must be set before any values are taken from the grid"
+        );
+    }
+}
+
+/// A grid of the indices along each axis, to test the traversals of a grid against
+#[cfg(test)]
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct GridIndex<const N: usize>([usize; N], Odometer<N>);
+
+#[cfg(test)]
+impl<const N: usize> GridIndex<N> {
+    pub(crate) fn space(lens: [usize; N]) -> Space<Self> {
+        Space::new(lens.iter().product(), GridIndex(lens, Odometer::new()))
+    }
+}
+
+#[cfg(test)]
+impl<const N: usize> crate::Interpolate for GridIndex<N> {
+    type Item = [usize; N];
+    fn interpolate(self, x: usize) -> [usize; N] {
+        self.1.split(x, self.0)
+    }
+}
+
+#[cfg(test)]
+impl<const N: usize> GridInterpolation<N> for GridIndex<N> {
+    fn lens(&self) -> [usize; N] {
+        self.0
+    }
+
+    fn odometer_mut(&mut self) -> &mut Odometer<N> {
+        &mut self.1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_odometer() {
+        let lens = [3, 1, 4, 2];
+        let len = lens.iter().product();
+
+        let orders = [[0, 1, 2, 3], [3, 2, 1, 0], [2, 0, 3, 1]];
+        for (axes, serpentine) in orders
+            .into_iter()
+            .flat_map(|axes| [(axes, false), (axes, true)])
+        {
+            let odometer = Odometer::new().with_axes(axes).with_serpentine(serpentine);
+            let split = |x| odometer.split(x, lens);
+
+            let mut front = odometer;
+            for x in 0..len {
+                assert_eq!(front.next(x, lens), split(x));
+            }
+            let mut back = odometer;
+            for x in (0..len).rev() {
+                assert_eq!(back.next_back(x, lens), split(x));
+            }
+
+            // jumping around falls back to splitting the index
+            let mut front = odometer;
+            for x in [5, 6, 2, 3, 4, 20, 21] {
+                assert_eq!(front.next(x, lens), split(x));
+            }
+            let mut back = odometer;
+            for x in [21, 20, 7, 6, 5, 0] {
+                assert_eq!(back.next_back(x, lens), split(x));
+            }
+
+            // each axis can be found from the flat index on its own
+            let strides = odometer.strides(lens);
+            for x in 0..len {
+                let index = split(x);
+                for axis in 0..4 {
+                    let z = odometer.axis_index(x / strides[axis], lens[axis]);
+                    assert_eq!(z, index[axis]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_odometer_serpentine() {
+        let lens = [3, 2, 3];
+        let odometer = Odometer::new().with_serpentine(true);
+        let points: Vec<_> = (0..18).map(|x| odometer.split(x, lens)).collect();
+        assert_eq!(
+            points[..9],
+            [
+                [0, 0, 0],
+                [1, 0, 0],
+                [2, 0, 0],
+                [2, 1, 0],
+                [1, 1, 0],
+                [0, 1, 0],
+                [0, 1, 1],
+                [1, 1, 1],
+                [2, 1, 1],
+            ]
+        );
+        // every step moves a single axis by one
+        for pair in points.windows(2) {
+            let moved: usize = (0..3).map(|i| pair[0][i].abs_diff(pair[1][i])).sum();
+            assert_eq!(moved, 1, "{pair:?}");
+        }
+    }
+}
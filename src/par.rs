@@ -0,0 +1,183 @@
+use rayon::iter::plumbing::{bridge, Consumer, Producer, ProducerCallback, UnindexedConsumer};
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+
+use crate::space::{Interpolate, IntoSpace, Space};
+
+/// [`IndexedParallelIterator`] over the values of a [`Space`], created by
+/// [`into_par_iter`](IntoParallelIterator::into_par_iter) or
+/// [`par_iter`](rayon::iter::IntoParallelRefIterator::par_iter).
+///
+/// The space is split between threads by index range, so no values are computed twice.
+///
+/// ```
+/// use iter_num_tools::{grid_space, lin_space};
+/// use rayon::prelude::*;
+///
+/// let total: f64 = lin_space(0.0..=1.0, 101).into_par_iter().sum();
+/// assert!((total - 50.5).abs() < 1e-10);
+///
+/// let it = grid_space([0.0, 0.0]..=[1.0, 1.0], [3, 3]);
+/// let points: Vec<[f64; 2]> = it.par_iter().collect();
+/// assert!(it.eq(points));
+/// ```
+#[derive(Clone, Debug)]
+pub struct ParSpace<I> {
+    space: Space<I>,
+}
+
+impl<I> IntoParallelIterator for Space<I>
+where
+    I: Interpolate + Copy + Send + Sync,
+    I::Item: Send,
+{
+    type Iter = ParSpace<I>;
+    type Item = I::Item;
+
+    fn into_par_iter(self) -> Self::Iter {
+        ParSpace { space: self }
+    }
+}
+
+impl<I> IntoParallelIterator for &Space<I>
+where
+    I: Interpolate + Copy + Send + Sync,
+    I::Item: Send,
+{
+    type Iter = ParSpace<I>;
+    type Item = I::Item;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.clone().into_par_iter()
+    }
+}
+
+impl<I> IntoParallelIterator for IntoSpace<I>
+where
+    I: Interpolate + Copy + Send + Sync,
+    I::Item: Send,
+{
+    type Iter = ParSpace<I>;
+    type Item = I::Item;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.into_space().into_par_iter()
+    }
+}
+
+impl<I> IntoParallelIterator for &IntoSpace<I>
+where
+    I: Interpolate + Copy + Send + Sync,
+    I::Item: Send,
+{
+    type Iter = ParSpace<I>;
+    type Item = I::Item;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.into_space().into_par_iter()
+    }
+}
+
+impl<I> ParallelIterator for ParSpace<I>
+where
+    I: Interpolate + Copy + Send + Sync,
+    I::Item: Send,
+{
+    type Item = I::Item;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.space.len())
+    }
+}
+
+impl<I> IndexedParallelIterator for ParSpace<I>
+where
+    I: Interpolate + Copy + Send + Sync,
+    I::Item: Send,
+{
+    fn len(&self) -> usize {
+        self.space.len()
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        callback.callback(SpaceProducer(self.space))
+    }
+}
+
+struct SpaceProducer<I>(Space<I>);
+
+impl<I> Producer for SpaceProducer<I>
+where
+    I: Interpolate + Copy + Send + Sync,
+    I::Item: Send,
+{
+    type Item = I::Item;
+    type IntoIter = Space<I>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let (left, right) = self.0.split_at(index);
+        (SpaceProducer(left), SpaceProducer(right))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rayon::prelude::*;
+
+    use crate::{arange, arange_grid, grid_space, grid_step, lin_space, log_space};
+
+    #[test]
+    fn test_par_lin_space() {
+        let it = lin_space(0.0..=1.0, 1001);
+        let par: Vec<f64> = it.par_iter().collect();
+        assert!(it.clone().eq(par));
+
+        let rev: Vec<f64> = it.clone().into_par_iter().rev().collect();
+        assert!(it.rev().eq(rev));
+    }
+
+    #[test]
+    fn test_par_log_space_and_arange() {
+        let it = log_space(1.0..=1e6, 1000);
+        assert!(it.clone().eq(it.par_iter().collect::<Vec<_>>()));
+
+        let it = arange(0.0..10.0, 0.1);
+        assert_eq!(it.par_iter().len(), 100);
+        assert!(it.clone().eq(it.par_iter().collect::<Vec<_>>()));
+    }
+
+    #[test]
+    fn test_par_grids() {
+        let it = grid_space([0.0, 0.0]..=[1.0, 2.0], [20, 30]);
+        assert!(it.clone().eq(it.par_iter().collect::<Vec<_>>()));
+
+        let it = grid_step([0, 0]..[5, 6]);
+        assert!(it.clone().eq(it.par_iter().collect::<Vec<_>>()));
+
+        let it = arange_grid([0.0, 0.0]..[1.0, 2.0], 0.1);
+        assert!(it.clone().eq(it.par_iter().collect::<Vec<_>>()));
+    }
+
+    #[test]
+    fn test_par_partially_consumed() {
+        let mut it = lin_space(0.0..=1.0, 11);
+        it.next();
+        it.next_back();
+        let par: Vec<f64> = it.par_iter().with_min_len(2).collect();
+        assert!(it.eq(par));
+    }
+}
@@ -1,26 +1,120 @@
 use core::iter::FusedIterator;
-use core::ops::Range;
+use core::ops::{Bound, Range, RangeBounds};
 
+/// A mapping from an index to a value, used to drive a [`Space`]
+///
+/// Every iterator in this crate is a [`Space`] over some `Interpolate` implementation.
+/// Implementing this trait for your own type gives you an iterator with the same
+/// [`ExactSizeIterator`], [`DoubleEndedIterator`] and `nth` behaviour.
+///
+/// ```
+/// use iter_num_tools::{Interpolate, Space};
+///
+/// #[derive(Clone, Copy)]
+/// struct Squares;
+///
+/// impl Interpolate for Squares {
+///     type Item = usize;
+///     fn interpolate(self, x: usize) -> usize {
+///         x * x
+///     }
+/// }
+///
+/// let it = Space::new(5, Squares);
+/// assert!(it.clone().eq([0, 1, 4, 9, 16]));
+/// assert!(it.rev().eq([16, 9, 4, 1, 0]));
+/// ```
 pub trait Interpolate {
+    /// The value produced at each index
     type Item;
+    /// Compute the value at index `x`. `x` will always be less than the length of the space
     fn interpolate(self, x: usize) -> Self::Item;
+
+    /// Compute the value at index `x` for [`Space::next`].
+    /// `x` is usually one more than in the previous call, so implementations
+    /// can keep some state to compute it faster than from scratch.
+    ///
+    /// The default calls [`interpolate`](Interpolate::interpolate)
+    fn interpolate_next(&mut self, x: usize) -> Self::Item
+    where
+        Self: Copy + Sized,
+    {
+        self.interpolate(x)
+    }
+
+    /// Compute the value at index `x` for [`Space::next_back`](DoubleEndedIterator::next_back).
+    /// `x` is usually one less than in the previous call.
+    ///
+    /// The default calls [`interpolate`](Interpolate::interpolate)
+    fn interpolate_next_back(&mut self, x: usize) -> Self::Item
+    where
+        Self: Copy + Sized,
+    {
+        self.interpolate(x)
+    }
+
+    /// Write the values from index `start` onwards into `out`.
+    /// `start + out.len()` will never be more than the length of the space.
+    ///
+    /// The default calls [`interpolate`](Interpolate::interpolate) for each index.
+    /// Override it when a run of values can be computed in a tighter loop
+    fn fill(self, start: usize, out: &mut [Self::Item])
+    where
+        Self: Copy + Sized,
+    {
+        for (x, out) in (start..).zip(out) {
+            *out = self.interpolate(x);
+        }
+    }
 }
 
+/// An [`IntoIterator`] over an [`Interpolate`] with a fixed length
 #[derive(Clone, Copy, Debug)]
 pub struct IntoSpace<I> {
+    /// The interpolation used to compute each value
     pub interpolate: I,
+    /// The number of values in the space
     pub len: usize,
 }
 
 impl<I> IntoSpace<I> {
+    /// Create a new space of `len` values computed by `interpolate`
     pub fn new(len: usize, interpolate: I) -> Self {
         IntoSpace { interpolate, len }
     }
+    /// Convert into the [`Space`] iterator
     pub fn into_space(self) -> Space<I> {
         Space::new(self.len, self.interpolate)
     }
 }
 
+impl<I: Interpolate + Copy> IntoSpace<I> {
+    /// Get the value at index `i`, or `None` if it is out of bounds
+    ///
+    /// ```
+    /// use iter_num_tools::ToLinSpace;
+    ///
+    /// let space = (0.0..=1.0).into_lin_space(5);
+    /// assert_eq!(space.get(1), Some(0.25));
+    /// assert_eq!(space.get(5), None);
+    /// assert_eq!(space.first(), Some(0.0));
+    /// assert_eq!(space.last(), Some(1.0));
+    /// ```
+    pub fn get(&self, i: usize) -> Option<I::Item> {
+        (i < self.len).then(|| self.interpolate.interpolate(i))
+    }
+
+    /// Get the first value, or `None` if the space is empty
+    pub fn first(&self) -> Option<I::Item> {
+        self.get(0)
+    }
+
+    /// Get the last value, or `None` if the space is empty
+    pub fn last(&self) -> Option<I::Item> {
+        self.get(self.len.checked_sub(1)?)
+    }
+}
+
 impl<I: Interpolate + Copy> IntoIterator for IntoSpace<I> {
     type Item = I::Item;
     type IntoIter = Space<I>;
@@ -30,13 +124,15 @@ impl<I: Interpolate + Copy> IntoIterator for IntoSpace<I> {
     }
 }
 
+/// An [`Iterator`] over the values of an [`Interpolate`] for the indices `0..len`
 #[derive(Clone, Debug)]
 pub struct Space<I> {
-    interpolate: I,
-    range: Range<usize>,
+    pub(crate) interpolate: I,
+    pub(crate) range: Range<usize>,
 }
 
 impl<I> Space<I> {
+    /// Create a new space of `len` values computed by `interpolate`
     pub fn new(len: usize, interpolate: I) -> Self {
         Space {
             interpolate,
@@ -45,11 +141,230 @@ impl<I> Space<I> {
     }
 }
 
+impl<I> Space<I> {
+    /// The indices of the values left in the iterator, out of the values it started with
+    ///
+    /// ```
+    /// use iter_num_tools::lin_space;
+    ///
+    /// let mut it = lin_space(0.0..=1.0, 5);
+    /// it.next();
+    /// it.next_back();
+    /// assert_eq!(it.remaining_range(), 1..4);
+    /// ```
+    pub fn remaining_range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    /// Narrow the iterator down to the given range of values, counted from the front of the iterator
+    ///
+    /// # Panics
+    ///
+    /// If the range is out of bounds of the values remaining, or the start of the range is after the end
+    ///
+    /// ```
+    /// use iter_num_tools::lin_space;
+    ///
+    /// let it = lin_space(0.0..=1.0, 5).slice(1..4);
+    /// assert!(it.eq([0.25, 0.5, 0.75]));
+    ///
+    /// let it = lin_space(0.0..=1.0, 5).slice(3..);
+    /// assert!(it.rev().eq([1.0, 0.75]));
+    /// ```
+    pub fn slice(self, range: impl RangeBounds<usize>) -> Self {
+        let len = self.range.len();
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.checked_add(1).expect("slice start overflowed"),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.checked_add(1).expect("slice end overflowed"),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => len,
+        };
+        assert!(start <= end, "slice start {start} is after end {end}");
+        assert!(
+            end <= len,
+            "slice end {end} is out of bounds of a space of length {len}"
+        );
+
+        let offset = self.range.start;
+        Space {
+            interpolate: self.interpolate,
+            range: offset + start..offset + end,
+        }
+    }
+
+    /// Split the iterator into two, before and after the value at `mid`
+    ///
+    /// # Panics
+    ///
+    /// If `mid` is greater than the number of values remaining
+    ///
+    /// ```
+    /// use iter_num_tools::lin_space;
+    ///
+    /// let (left, right) = lin_space(0.0..=1.0, 5).split_at(2);
+    /// assert!(left.eq([0.0, 0.25]));
+    /// assert!(right.eq([0.5, 0.75, 1.0]));
+    /// ```
+    pub fn split_at(self, mid: usize) -> (Self, Self)
+    where
+        I: Copy,
+    {
+        let len = self.range.len();
+        assert!(
+            mid <= len,
+            "split point {mid} is out of bounds of a space of length {len}"
+        );
+
+        let mid = self.range.start + mid;
+        let left = Space {
+            interpolate: self.interpolate,
+            range: self.range.start..mid,
+        };
+        let right = Space {
+            interpolate: self.interpolate,
+            range: mid..self.range.end,
+        };
+        (left, right)
+    }
+
+    /// Creates a space that starts at the front of this iterator, and steps over `step` values each time.
+    /// Like [`Iterator::step_by`], but the result is still a [`Space`]
+    ///
+    /// # Panics
+    ///
+    /// If `step` is zero
+    ///
+    /// ```
+    /// use iter_num_tools::lin_space;
+    ///
+    /// let it = lin_space(0.0..=1.0, 5).step_by_exact(2);
+    /// assert_eq!(it.len(), 3);
+    /// assert!(it.rev().eq([1.0, 0.5, 0.0]));
+    /// ```
+    pub fn step_by_exact(self, step: usize) -> Space<Stride<I>> {
+        assert!(step != 0, "step must not be zero");
+        let len = self.range.len().div_ceil(step);
+        Space::new(
+            len,
+            Stride {
+                interpolate: self.interpolate,
+                start: self.range.start,
+                step,
+            },
+        )
+    }
+}
+
+impl<I: Interpolate + Copy> Space<I> {
+    /// Creates a space over the values in fixed size arrays of `L` lanes.
+    /// Like [`slice::chunks_exact`], any values left over at the end
+    /// are not included, and are available from [`remainder`](Space::remainder) instead
+    ///
+    /// Each chunk is computed with [`Interpolate::fill`], so linear spaces
+    /// compute all the lanes together rather than one at a time.
+    ///
+    /// # Panics
+    ///
+    /// If `L` is zero
+    ///
+    /// ```
+    /// use iter_num_tools::lin_space;
+    ///
+    /// let it = lin_space(0.0..5.0, 10).chunks_exact::<4>();
+    /// assert_eq!(it.len(), 2);
+    /// assert!(it.remainder().eq([4.0, 4.5]));
+    /// assert!(it.eq([[0.0, 0.5, 1.0, 1.5], [2.0, 2.5, 3.0, 3.5]]));
+    /// ```
+    pub fn chunks_exact<const L: usize>(self) -> Space<ChunksExact<I, L>>
+    where
+        I::Item: Copy,
+    {
+        assert!(L != 0, "chunk size must not be zero");
+        Space::new(
+            self.range.len() / L,
+            ChunksExact {
+                interpolate: self.interpolate,
+                start: self.range.start,
+                end: self.range.end,
+            },
+        )
+    }
+
+    /// Write the next values of the iterator into `buf`, returning how many were written.
+    /// This is fewer than `buf.len()` only if the iterator runs out of values first.
+    ///
+    /// This avoids going through [`next`](Iterator::next) for every value,
+    /// which lets the compiler vectorize the loop for the spaces that support it.
+    ///
+    /// ```
+    /// use iter_num_tools::lin_space;
+    ///
+    /// let mut it = lin_space(0.0..=1.0, 5);
+    /// let mut buf = [0.0; 3];
+    ///
+    /// assert_eq!(it.fill_into(&mut buf), 3);
+    /// assert_eq!(buf, [0.0, 0.25, 0.5]);
+    ///
+    /// assert_eq!(it.fill_into(&mut buf), 2);
+    /// assert_eq!(buf[..2], [0.75, 1.0]);
+    /// ```
+    pub fn fill_into(&mut self, buf: &mut [I::Item]) -> usize {
+        let n = buf.len().min(self.range.len());
+        self.interpolate.fill(self.range.start, &mut buf[..n]);
+        self.range.start += n;
+        n
+    }
+
+    /// Get the value `i` places after the front of the iterator, without consuming it.
+    /// Returns `None` if that is past the back of the iterator
+    ///
+    /// ```
+    /// use iter_num_tools::lin_space;
+    ///
+    /// let mut it = lin_space(0.0..=1.0, 5);
+    /// assert_eq!(it.get(1), Some(0.25));
+    ///
+    /// it.next();
+    /// assert_eq!(it.get(1), Some(0.5));
+    /// assert_eq!(it.peek(), Some(0.25));
+    /// assert_eq!(it.peek_back(), Some(1.0));
+    /// assert_eq!(it.get(4), None);
+    /// ```
+    pub fn get(&self, i: usize) -> Option<I::Item> {
+        let x = self.range.start.checked_add(i)?;
+        (x < self.range.end).then(|| self.interpolate.interpolate(x))
+    }
+
+    /// Get the value at the front of the iterator, without consuming it
+    pub fn peek(&self) -> Option<I::Item> {
+        self.get(0)
+    }
+
+    /// Get the value at the front of the iterator, without consuming it.
+    /// The same as [`Space::peek`], to match [`IntoSpace::first`]
+    pub fn first(&self) -> Option<I::Item> {
+        self.peek()
+    }
+
+    /// Get the value at the back of the iterator, without consuming it.
+    ///
+    /// Unlike [`IntoSpace::last`], this is not called `last`, since [`Iterator::last`]
+    /// takes the iterator by value and would be picked over a method taking `&self`
+    pub fn peek_back(&self) -> Option<I::Item> {
+        self.get(self.len().checked_sub(1)?)
+    }
+}
+
 impl<I: Interpolate + Copy> Iterator for Space<I> {
     type Item = I::Item;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.range.next().map(|x| self.interpolate.interpolate(x))
+        let x = self.range.next()?;
+        Some(self.interpolate.interpolate_next(x))
     }
 
     fn count(self) -> usize
@@ -75,6 +390,35 @@ impl<I: Interpolate + Copy> Iterator for Space<I> {
         self.range.nth(n).map(|x| self.interpolate.interpolate(x))
     }
 
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        let Space {
+            mut interpolate,
+            range,
+        } = self;
+        range.fold(init, |acc, x| f(acc, interpolate.interpolate_next(x)))
+    }
+
+    #[cfg(feature = "try_trait_v2")]
+    fn try_fold<B, F, R>(&mut self, init: B, mut f: F) -> R
+    where
+        F: FnMut(B, Self::Item) -> R,
+        R: core::ops::Try<Output = B>,
+    {
+        let interpolate = &mut self.interpolate;
+        self.range
+            .try_fold(init, |acc, x| f(acc, interpolate.interpolate_next(x)))
+    }
+
+    fn for_each<F>(self, mut f: F)
+    where
+        F: FnMut(Self::Item),
+    {
+        self.fold((), |(), item| f(item));
+    }
+
     fn size_hint(&self) -> (usize, Option<usize>) {
         let len = self.len();
         (len, Some(len))
@@ -84,9 +428,8 @@ impl<I: Interpolate + Copy> Iterator for Space<I> {
 impl<I: Interpolate + Copy> DoubleEndedIterator for Space<I> {
     #[inline]
     fn next_back(&mut self) -> Option<Self::Item> {
-        self.range
-            .next_back()
-            .map(|x| self.interpolate.interpolate(x))
+        let x = self.range.next_back()?;
+        Some(self.interpolate.interpolate_next_back(x))
     }
 
     #[cfg(feature = "iter_advance_by")]
@@ -99,6 +442,28 @@ impl<I: Interpolate + Copy> DoubleEndedIterator for Space<I> {
             .nth_back(n)
             .map(|x| self.interpolate.interpolate(x))
     }
+
+    fn rfold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        let Space {
+            mut interpolate,
+            range,
+        } = self;
+        range.rfold(init, |acc, x| f(acc, interpolate.interpolate_next_back(x)))
+    }
+
+    #[cfg(feature = "try_trait_v2")]
+    fn try_rfold<B, F, R>(&mut self, init: B, mut f: F) -> R
+    where
+        F: FnMut(B, Self::Item) -> R,
+        R: core::ops::Try<Output = B>,
+    {
+        let interpolate = &mut self.interpolate;
+        self.range
+            .try_rfold(init, |acc, x| f(acc, interpolate.interpolate_next_back(x)))
+    }
 }
 
 impl<I: Interpolate + Copy> ExactSizeIterator for Space<I> {
@@ -110,7 +475,272 @@ impl<I: Interpolate + Copy> ExactSizeIterator for Space<I> {
 
 impl<I: Interpolate + Copy> FusedIterator for Space<I> {}
 
+/// An [`Interpolate`] over every `step`th value of another, created by [`Space::step_by_exact`]
+#[derive(Clone, Copy, Debug)]
+pub struct Stride<I> {
+    /// The interpolation being stepped over
+    pub interpolate: I,
+    /// The index into `interpolate` of the first value
+    pub start: usize,
+    /// How many indices of `interpolate` to move for each value
+    pub step: usize,
+}
+
+impl<I: Interpolate> Interpolate for Stride<I> {
+    type Item = I::Item;
+    fn interpolate(self, x: usize) -> I::Item {
+        self.interpolate.interpolate(self.start + x * self.step)
+    }
+}
+
+/// An [`Interpolate`] over arrays of `L` consecutive values of another, created by [`Space::chunks_exact`]
+#[derive(Clone, Copy, Debug)]
+pub struct ChunksExact<I, const L: usize> {
+    /// The interpolation being chunked
+    pub interpolate: I,
+    /// The index into `interpolate` of the first value of the first chunk
+    pub start: usize,
+    /// The index into `interpolate` after the last value, including the remainder
+    pub end: usize,
+}
+
+impl<I, const L: usize> Interpolate for ChunksExact<I, L>
+where
+    I: Interpolate + Copy,
+    I::Item: Copy,
+{
+    type Item = [I::Item; L];
+    fn interpolate(self, x: usize) -> [I::Item; L] {
+        let base = self.start + x * L;
+        let mut lanes = [self.interpolate.interpolate(base); L];
+        self.interpolate.fill(base + 1, &mut lanes[1..]);
+        lanes
+    }
+}
+
+impl<I: Copy, const L: usize> Space<ChunksExact<I, L>> {
+    /// The values left over at the end that do not fill a whole chunk.
+    /// These are the same no matter how many chunks have been taken from the iterator
+    pub fn remainder(&self) -> Space<I> {
+        let ChunksExact {
+            interpolate,
+            start,
+            end,
+        } = self.interpolate;
+        Space {
+            interpolate,
+            range: end - (end - start) % L..end,
+        }
+    }
+}
+
 #[cfg(feature = "trusted_len")]
 use core::iter::TrustedLen;
 #[cfg(feature = "trusted_len")]
 unsafe impl<I: Interpolate + Copy> TrustedLen for Space<I> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::check_double_ended_iter;
+
+    use super::*;
+
+    #[derive(Clone, Copy)]
+    struct Double;
+
+    impl Interpolate for Double {
+        type Item = usize;
+        fn interpolate(self, x: usize) -> usize {
+            x * 2
+        }
+    }
+
+    #[test]
+    fn test_custom_space() {
+        check_double_ended_iter(Space::new(4, Double), [0, 2, 4, 6]);
+        check_double_ended_iter(IntoSpace::new(3, Double).into_iter(), [0, 2, 4]);
+    }
+
+    #[test]
+    fn test_space_get() {
+        let mut it = Space::new(6, Double);
+        assert_eq!(it.get(0), Some(0));
+        assert_eq!(it.get(5), Some(10));
+        assert_eq!(it.get(6), None);
+        assert_eq!(it.get(usize::MAX), None);
+
+        it.nth(1);
+        it.nth_back(1);
+        assert_eq!(it.len(), 2);
+        assert_eq!(it.get(0), Some(4));
+        assert_eq!(it.get(1), Some(6));
+        assert_eq!(it.get(2), None);
+        assert_eq!(it.peek(), Some(4));
+        assert_eq!(it.first(), Some(4));
+        assert_eq!(it.peek_back(), Some(6));
+
+        // peeking does not consume
+        assert_eq!(it.len(), 2);
+        it.by_ref().for_each(drop);
+        assert_eq!(it.peek(), None);
+        assert_eq!(it.first(), None);
+        assert_eq!(it.peek_back(), None);
+    }
+
+    #[test]
+    fn test_into_space_get() {
+        let space = IntoSpace::new(4, Double);
+        assert_eq!(space.get(3), Some(6));
+        assert_eq!(space.get(4), None);
+        assert_eq!(space.first(), Some(0));
+        assert_eq!(space.last(), Some(6));
+
+        let empty = IntoSpace::new(0, Double);
+        assert_eq!(empty.first(), None);
+        assert_eq!(empty.last(), None);
+    }
+
+    #[test]
+    fn test_space_fill_into() {
+        let mut it = Space::new(6, Double);
+        it.next_back();
+        let mut buf = [0; 4];
+        assert_eq!(it.fill_into(&mut buf[..2]), 2);
+        assert_eq!(buf, [0, 2, 0, 0]);
+        assert_eq!(it.fill_into(&mut buf), 3);
+        assert_eq!(buf, [4, 6, 8, 0]);
+        assert_eq!(it.fill_into(&mut buf), 0);
+        assert_eq!(it.next(), None);
+
+        let mut it = Space::new(10, Double).step_by_exact(3);
+        assert_eq!(it.fill_into(&mut buf), 4);
+        assert_eq!(buf, [0, 6, 12, 18]);
+    }
+
+    #[test]
+    fn test_space_chunks_exact() {
+        let it = Space::new(7, Double).chunks_exact::<3>();
+        check_double_ended_iter(it.clone(), [[0, 2, 4], [6, 8, 10]]);
+        check_double_ended_iter(it.remainder(), [12]);
+
+        let mut it = Space::new(7, Double);
+        it.next();
+        let mut it = it.chunks_exact::<2>();
+        assert_eq!(it.nth_back(0), Some([10, 12]));
+        assert_eq!(it.get(1), Some([6, 8]));
+        check_double_ended_iter(it.remainder(), []);
+
+        let it = Space::new(2, Double).chunks_exact::<4>();
+        check_double_ended_iter(it.clone(), []);
+        check_double_ended_iter(it.remainder(), [0, 2]);
+    }
+
+    #[test]
+    #[should_panic = "chunk size must not be zero"]
+    fn test_space_chunks_exact_zero() {
+        Space::new(7, Double).chunks_exact::<0>();
+    }
+
+    #[test]
+    fn test_space_internal_iteration() {
+        let it = Space::new(6, Double);
+        assert_eq!(
+            it.clone().fold(vec![], |mut v, x| {
+                v.push(x);
+                v
+            }),
+            [0, 2, 4, 6, 8, 10]
+        );
+        assert_eq!(
+            it.clone().rfold(vec![], |mut v, x| {
+                v.push(x);
+                v
+            }),
+            [10, 8, 6, 4, 2, 0]
+        );
+
+        let mut values = vec![];
+        it.clone().for_each(|x| values.push(x));
+        assert_eq!(values, [0, 2, 4, 6, 8, 10]);
+
+        // stopping early leaves the rest of the iterator intact
+        let mut it = it;
+        assert_eq!(it.try_fold(0, |acc, x| (x < 4).then_some(acc + x)), None);
+        assert_eq!(it.try_rfold(0, |acc, x| (x > 8).then_some(acc + x)), None);
+        check_double_ended_iter(it.clone(), [6]);
+        assert_eq!(it.try_fold(0, |acc, x| Some(acc + x)), Some(6));
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn test_space_slice() {
+        check_double_ended_iter(Space::new(6, Double).slice(1..4), [2, 4, 6]);
+        check_double_ended_iter(Space::new(6, Double).slice(..=1), [0, 2]);
+        check_double_ended_iter(Space::new(6, Double).slice(4..), [8, 10]);
+        check_double_ended_iter(Space::new(6, Double).slice(6..), []);
+
+        // relative to the front of the iterator
+        let mut it = Space::new(6, Double);
+        it.next();
+        it.next_back();
+        check_double_ended_iter(it.clone().slice(1..3), [4, 6]);
+        check_double_ended_iter(it.slice(..), [2, 4, 6, 8]);
+    }
+
+    #[test]
+    #[should_panic = "out of bounds"]
+    fn test_space_slice_out_of_bounds() {
+        let mut it = Space::new(6, Double);
+        it.next();
+        it.slice(..6);
+    }
+
+    #[test]
+    fn test_space_split_at() {
+        let (left, right) = Space::new(6, Double).split_at(2);
+        check_double_ended_iter(left, [0, 2]);
+        check_double_ended_iter(right, [4, 6, 8, 10]);
+
+        let mut it = Space::new(6, Double);
+        it.next();
+        let (left, right) = it.split_at(5);
+        check_double_ended_iter(left, [2, 4, 6, 8, 10]);
+        check_double_ended_iter(right, []);
+    }
+
+    #[test]
+    fn test_space_step_by_exact() {
+        check_double_ended_iter(Space::new(6, Double).step_by_exact(2), [0, 4, 8]);
+        check_double_ended_iter(Space::new(7, Double).step_by_exact(3), [0, 6, 12]);
+        check_double_ended_iter(Space::new(6, Double).step_by_exact(10), [0]);
+        check_double_ended_iter(Space::new(0, Double).step_by_exact(2), []);
+
+        // matches the std adaptor
+        let it = Space::new(10, Double);
+        for step in 1..12 {
+            let mut it = it.clone();
+            it.next();
+            let expected: Vec<_> = it.clone().step_by(step).collect();
+            assert_eq!(it.step_by_exact(step).collect::<Vec<_>>(), expected);
+        }
+
+        // and composes with the other operations
+        let it = Space::new(10, Double)
+            .step_by_exact(2)
+            .slice(1..4)
+            .step_by_exact(2);
+        check_double_ended_iter(it, [4, 12]);
+        let mut it = Space::new(10, Double).step_by_exact(3);
+        assert_eq!(it.nth_back(1), Some(12));
+        assert_eq!(it.get(1), Some(6));
+    }
+
+    #[test]
+    fn test_custom_space_nth() {
+        let mut it = IntoSpace::new(6, Double).into_space();
+        assert_eq!(it.nth(1), Some(2));
+        assert_eq!(it.nth_back(1), Some(8));
+        assert_eq!(it.len(), 2);
+        assert_eq!(it.last(), Some(6));
+    }
+}
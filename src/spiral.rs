@@ -0,0 +1,311 @@
+use core::iter::FusedIterator;
+
+use crate::{
+    odometer::GridInterpolation,
+    space::{Interpolate, Space},
+};
+
+/// A position along the spiral, as the ring, the side of the ring and the offset along that side
+type Cursor = (usize, usize, usize);
+
+/// [`Iterator`] over the points of a 2D grid in a square spiral out from the centre,
+/// created by [`Space::spiral`].
+///
+/// Each ring is the square of points at the same distance along the furthest axis from the centre.
+/// Rings that do not fit in the grid are cut short, skipping the points outside of it.
+#[derive(Clone, Debug)]
+pub struct GridSpiral<I> {
+    interpolate: I,
+    lens: [usize; 2],
+    /// The next position to try from the front
+    front: Cursor,
+    /// The position after the next one to try from the back
+    back: Cursor,
+    /// How many points are left between `front` and `back`
+    remaining: usize,
+}
+
+impl<I: GridInterpolation<2>> Space<I> {
+    /// Visit the points of a 2D grid in a square spiral out from the centre,
+    /// so each point is a single step along one axis from the last when the grid is square with an odd length.
+    /// When an axis has an even length, the centre rounds down along it.
+    /// This replaces the [`GridOrder`](crate::GridOrder) and serpentine order
+    ///
+    /// # Panics
+    ///
+    /// If values have already been taken from the iterator
+    ///
+    /// ```
+    /// use iter_num_tools::{grid_space, grid_step};
+    ///
+    /// let it = grid_space([-1.0, -1.0]..=[1.0, 1.0], 3).spiral();
+    /// assert!(it.eq([
+    ///     [0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0], [-1.0, 1.0],
+    ///     [-1.0, 0.0], [-1.0, -1.0], [0.0, -1.0], [1.0, -1.0],
+    /// ]));
+    ///
+    /// // along with the ring of each point
+    /// let mut it = grid_step([-2, -2]..=[2, 2]).spiral().rings();
+    /// assert_eq!(it.nth(9), Some((2, [2, -1])));
+    /// ```
+    pub fn spiral(self) -> GridSpiral<I> {
+        self.assert_unstarted("spiral order");
+        GridSpiral::new(self.interpolate)
+    }
+}
+
+impl<I: GridInterpolation<2>> GridSpiral<I> {
+    fn new(interpolate: I) -> Self {
+        let lens = interpolate.lens();
+        let rings = lens[0].max(lens[1]) / 2;
+        GridSpiral {
+            interpolate: interpolate.unordered(),
+            lens,
+            front: (0, 0, 0),
+            back: (rings, 3, side_len(rings)),
+            remaining: lens[0] * lens[1],
+        }
+    }
+}
+
+impl<I> GridSpiral<I> {
+    /// Iterate over the ring of each point along with the point
+    ///
+    /// ```
+    /// use iter_num_tools::grid_step;
+    ///
+    /// let it = grid_step([-1, 0]..=[1, 0]).spiral().rings();
+    /// assert!(it.eq([(0, [0, 0]), (1, [1, 0]), (1, [-1, 0])]));
+    /// ```
+    pub fn rings(self) -> SpiralRings<I> {
+        SpiralRings(self)
+    }
+
+    /// The index along each axis of the centre of the grid, rounding down
+    fn centre(&self) -> [usize; 2] {
+        self.lens.map(|len| len.saturating_sub(1) / 2)
+    }
+
+    /// The offsets along `side` of `ring` that are inside of the grid
+    fn side_range(&self, (ring, side): (usize, usize)) -> Option<(usize, usize)> {
+        if ring == 0 {
+            return (side == 0).then_some((0, 0));
+        }
+        let k = ring as isize;
+        let [cx, cy] = self.centre().map(|c| c as isize);
+        let [lx, ly] = self.lens.map(|len| len as isize);
+        // the offsets from the centre that are inside the grid along each axis
+        let (ax, bx) = (-cx, lx - 1 - cx);
+        let (ay, by) = (-cy, ly - 1 - cy);
+
+        let (fixed, lo, hi) = match side {
+            // up the right side
+            0 => (ax <= k && k <= bx, ay + k - 1, by + k - 1),
+            // left along the top
+            1 => (ay <= k && k <= by, k - 1 - bx, k - 1 - ax),
+            // down the left side
+            2 => (ax <= -k && -k <= bx, k - 1 - by, k - 1 - ay),
+            // right along the bottom
+            _ => (ay <= -k && -k <= by, ax + k - 1, bx + k - 1),
+        };
+        let (lo, hi) = (lo.max(0), hi.min(2 * k - 1));
+        (fixed && lo <= hi).then_some((lo as usize, hi as usize))
+    }
+
+    /// The flat index of the point at `t` along `side` of `ring`
+    fn flat_index(&self, (ring, side, t): Cursor) -> usize {
+        let [cx, cy] = self.centre();
+        let (x, y) = match side {
+            _ if ring == 0 => (cx, cy),
+            0 => (cx + ring, cy + t + 1 - ring),
+            1 => (cx + ring - 1 - t, cy + ring),
+            2 => (cx - ring, cy + ring - 1 - t),
+            _ => (cx + t + 1 - ring, cy - ring),
+        };
+        x + y * self.lens[0]
+    }
+
+    fn next_cursor(&mut self) -> Option<Cursor> {
+        while self.remaining > 0 {
+            let (ring, side, t) = self.front;
+            match self.side_range((ring, side)) {
+                Some((lo, hi)) if t <= hi => {
+                    let t = t.max(lo);
+                    self.front = (ring, side, t + 1);
+                    self.remaining -= 1;
+                    return Some((ring, side, t));
+                }
+                _ if side < 3 => self.front = (ring, side + 1, 0),
+                _ => self.front = (ring + 1, 0, 0),
+            }
+        }
+        None
+    }
+
+    fn next_back_cursor(&mut self) -> Option<Cursor> {
+        while self.remaining > 0 {
+            let (ring, side, end) = self.back;
+            match self.side_range((ring, side)) {
+                Some((lo, hi)) if lo < end => {
+                    let t = (end - 1).min(hi);
+                    self.back = (ring, side, t);
+                    self.remaining -= 1;
+                    return Some((ring, side, t));
+                }
+                _ if side > 0 => self.back = (ring, side - 1, side_len(ring)),
+                _ => self.back = (ring - 1, 3, side_len(ring - 1)),
+            }
+        }
+        None
+    }
+}
+
+/// The number of points along each side of a ring
+fn side_len(ring: usize) -> usize {
+    (2 * ring).max(1)
+}
+
+impl<I: Interpolate + Copy> Iterator for GridSpiral<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let cursor = self.next_cursor()?;
+        Some(self.interpolate.interpolate(self.flat_index(cursor)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<I: Interpolate + Copy> DoubleEndedIterator for GridSpiral<I> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let cursor = self.next_back_cursor()?;
+        Some(self.interpolate.interpolate(self.flat_index(cursor)))
+    }
+}
+
+impl<I: Interpolate + Copy> ExactSizeIterator for GridSpiral<I> {
+    fn len(&self) -> usize {
+        self.remaining
+    }
+}
+
+impl<I: Interpolate + Copy> FusedIterator for GridSpiral<I> {}
+
+/// [`Iterator`] over the points of a [`GridSpiral`] along with the ring they are in,
+/// where the centre is ring 0. Created by [`GridSpiral::rings`]
+#[derive(Clone, Debug)]
+pub struct SpiralRings<I>(GridSpiral<I>);
+
+impl<I: Interpolate + Copy> Iterator for SpiralRings<I> {
+    type Item = (usize, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let cursor = self.0.next_cursor()?;
+        Some((
+            cursor.0,
+            self.0.interpolate.interpolate(self.0.flat_index(cursor)),
+        ))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<I: Interpolate + Copy> DoubleEndedIterator for SpiralRings<I> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let cursor = self.0.next_back_cursor()?;
+        Some((
+            cursor.0,
+            self.0.interpolate.interpolate(self.0.flat_index(cursor)),
+        ))
+    }
+}
+
+impl<I: Interpolate + Copy> ExactSizeIterator for SpiralRings<I> {
+    fn len(&self) -> usize {
+        self.0.remaining
+    }
+}
+
+impl<I: Interpolate + Copy> FusedIterator for SpiralRings<I> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{check_meet_in_middle, odometer::GridIndex};
+
+    fn spiral(lens: [usize; 2]) -> GridSpiral<GridIndex<2>> {
+        GridIndex::space(lens).spiral()
+    }
+
+    #[test]
+    #[should_panic = "spiral order must be set before any values are taken"]
+    fn test_spiral_after_next_back() {
+        let mut it = GridIndex::space([3, 3]);
+        it.next_back();
+        let _ = it.spiral();
+    }
+
+    #[test]
+    fn test_spiral_square() {
+        let points: Vec<_> = spiral([3, 3]).collect();
+        assert_eq!(
+            points,
+            [
+                [1, 1],
+                [2, 1],
+                [2, 2],
+                [1, 2],
+                [0, 2],
+                [0, 1],
+                [0, 0],
+                [1, 0],
+                [2, 0],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_spiral_shapes() {
+        for lens in [
+            [0, 0],
+            [0, 3],
+            [1, 1],
+            [1, 6],
+            [6, 1],
+            [4, 4],
+            [5, 2],
+            [3, 8],
+            [7, 5],
+        ] {
+            let it = spiral(lens);
+            let len = lens[0] * lens[1];
+            assert_eq!(it.len(), len);
+
+            // every point once, each a single step from the last when the grid is an odd square
+            let points: Vec<_> = it.clone().collect();
+            let mut seen = vec![false; len];
+            for p in &points {
+                let x = p[0] + p[1] * lens[0];
+                assert!(!seen[x], "{lens:?} {p:?}");
+                seen[x] = true;
+            }
+            assert_eq!(points.len(), len);
+
+            // rings never get closer to the centre
+            let rings: Vec<_> = it.clone().rings().map(|(ring, _)| ring).collect();
+            assert!(rings.windows(2).all(|pair| pair[0] <= pair[1]));
+
+            check_meet_in_middle(it, &points);
+        }
+
+        let points: Vec<_> = spiral([5, 5]).collect();
+        for pair in points.windows(2) {
+            let moved = pair[0][0].abs_diff(pair[1][0]) + pair[0][1].abs_diff(pair[1][1]);
+            assert_eq!(moved, 1, "{pair:?}");
+        }
+    }
+}
@@ -0,0 +1,436 @@
+use core::iter::FusedIterator;
+
+use crate::{
+    odometer::GridInterpolation,
+    space::{Interpolate, Space},
+};
+
+/// [`Iterator`] over the points of a grid in wavefront (anti-diagonal) order,
+/// created by [`Space::wavefront`].
+///
+/// Every point where the indices along each axis add up to `k` comes before any point where they add up to `k + 1`.
+/// Within a wavefront, the points are in the default column-major order, so the index along the last axis never decreases.
+#[derive(Clone, Debug)]
+pub struct GridWavefront<I, const N: usize> {
+    interpolate: I,
+    lens: [usize; N],
+    /// The index along each axis of the next point from the front
+    front: [usize; N],
+    /// The index along each axis of the next point from the back
+    back: [usize; N],
+    /// How many points are left between `front` and `back`, inclusive
+    remaining: usize,
+}
+
+impl<I> Space<I> {
+    /// Visit the points of a grid in wavefront (anti-diagonal) order, where every point whose indices along each axis
+    /// add up to `k` comes before any that add up to `k + 1`.
+    /// This replaces the [`GridOrder`](crate::GridOrder) and serpentine order
+    ///
+    /// # Panics
+    ///
+    /// If values have already been taken from the iterator
+    ///
+    /// ```
+    /// use iter_num_tools::grid_space;
+    ///
+    /// let it = grid_space([0.0, 0.0]..=[2.0, 1.0], [3, 2]).wavefront();
+    /// assert!(it.eq([
+    ///     [0.0, 0.0],
+    ///     [1.0, 0.0], [0.0, 1.0],
+    ///     [2.0, 0.0], [1.0, 1.0],
+    ///     [2.0, 1.0],
+    /// ]));
+    /// ```
+    pub fn wavefront<const N: usize>(self) -> GridWavefront<I, N>
+    where
+        I: GridInterpolation<N>,
+    {
+        self.assert_unstarted("wavefront order");
+        GridWavefront::new(self.interpolate)
+    }
+
+    /// Iterate over each wavefront of a grid in turn, as in [`wavefront`](Self::wavefront),
+    /// with each one an [`ExactSizeIterator`] over just its points
+    ///
+    /// # Panics
+    ///
+    /// If values have already been taken from the iterator
+    ///
+    /// ```
+    /// use iter_num_tools::grid_step;
+    ///
+    /// // fill in a dynamic programming table one anti-diagonal at a time,
+    /// // where each cell only depends on the cells above and to the left
+    /// let mut table = [[0u64; 4]; 4];
+    /// for wavefront in grid_step([0, 0]..[4, 4]).wavefronts() {
+    ///     assert!(wavefront.len() <= 4);
+    ///     for [i, j] in wavefront {
+    ///         table[i][j] = match (i, j) {
+    ///             (0, _) | (_, 0) => 1,
+    ///             _ => table[i - 1][j] + table[i][j - 1],
+    ///         };
+    ///     }
+    /// }
+    /// assert_eq!(table[3][3], 20);
+    /// ```
+    pub fn wavefronts<const N: usize>(self) -> GridWavefronts<I, N>
+    where
+        I: GridInterpolation<N>,
+    {
+        self.assert_unstarted("wavefront order");
+        GridWavefronts::new(self.interpolate)
+    }
+}
+
+impl<I: GridInterpolation<N>, const N: usize> GridWavefront<I, N> {
+    fn new(interpolate: I) -> Self {
+        let lens = interpolate.lens();
+        let remaining = lens.iter().product();
+        let back = match remaining {
+            0 => [0; N],
+            _ => lens.map(|len| len - 1),
+        };
+        GridWavefront {
+            interpolate: interpolate.unordered(),
+            lens,
+            front: [0; N],
+            back,
+            remaining,
+        }
+    }
+}
+
+impl<I, const N: usize> GridWavefront<I, N> {
+    fn flat_index(&self, index: [usize; N]) -> usize {
+        let mut x = 0;
+        let mut stride = 1;
+        for (z, len) in index.into_iter().zip(self.lens) {
+            x += z * stride;
+            stride *= len;
+        }
+        x
+    }
+
+    fn next_index(&mut self) -> Option<[usize; N]> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let index = self.front;
+        self.remaining -= 1;
+        if self.remaining > 0 {
+            self.front = next_on_wavefront(self.lens, index)
+                .unwrap_or_else(|| first_on_wavefront(self.lens, index.iter().sum::<usize>() + 1));
+        }
+        Some(index)
+    }
+
+    fn next_back_index(&mut self) -> Option<[usize; N]> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let index = self.back;
+        self.remaining -= 1;
+        if self.remaining > 0 {
+            self.back = prev_on_wavefront(self.lens, index)
+                .unwrap_or_else(|| last_on_wavefront(self.lens, index.iter().sum::<usize>() - 1));
+        }
+        Some(index)
+    }
+}
+
+/// The first point where the indices add up to `k`, putting as much as possible on the fastest axes
+fn first_on_wavefront<const N: usize>(lens: [usize; N], k: usize) -> [usize; N] {
+    let mut index = [0; N];
+    fill_fastest(&mut index, &lens, k);
+    index
+}
+
+/// The last point where the indices add up to `k`, putting as much as possible on the slowest axes
+fn last_on_wavefront<const N: usize>(lens: [usize; N], k: usize) -> [usize; N] {
+    let mut index = [0; N];
+    fill_slowest(&mut index, &lens, k);
+    index
+}
+
+fn fill_fastest(index: &mut [usize], lens: &[usize], mut k: usize) {
+    for (z, len) in index.iter_mut().zip(lens) {
+        *z = k.min(len - 1);
+        k -= *z;
+    }
+}
+
+fn fill_slowest(index: &mut [usize], lens: &[usize], mut k: usize) {
+    for (z, len) in index.iter_mut().zip(lens).rev() {
+        *z = k.min(len - 1);
+        k -= *z;
+    }
+}
+
+/// The point after `index` with the same sum, by moving one from the faster axes
+/// onto the fastest axis that can take it
+fn next_on_wavefront<const N: usize>(
+    lens: [usize; N],
+    mut index: [usize; N],
+) -> Option<[usize; N]> {
+    // the sum of the indices along the axes faster than `i`
+    let mut faster = 0;
+    for i in 0..N {
+        if faster > 0 && index[i] + 1 < lens[i] {
+            index[i] += 1;
+            fill_fastest(&mut index[..i], &lens[..i], faster - 1);
+            return Some(index);
+        }
+        faster += index[i];
+    }
+    None
+}
+
+/// The point before `index` with the same sum, by moving one off the fastest axis that has any
+/// onto the faster axes
+fn prev_on_wavefront<const N: usize>(
+    lens: [usize; N],
+    mut index: [usize; N],
+) -> Option<[usize; N]> {
+    // the sum of the indices along the axes faster than `i`, and the most that they can hold
+    let mut faster = 0;
+    let mut capacity = 0;
+    for i in 0..N {
+        if index[i] > 0 && faster < capacity {
+            index[i] -= 1;
+            fill_slowest(&mut index[..i], &lens[..i], faster + 1);
+            return Some(index);
+        }
+        faster += index[i];
+        capacity += lens[i] - 1;
+    }
+    None
+}
+
+/// The number of points where the indices add up to `k`.
+///
+/// By inclusion-exclusion over the axes that are pushed past their end,
+/// with the binomials taken modulo 2^128 since only the total has to fit
+fn wavefront_len<const N: usize>(lens: [usize; N], k: usize) -> usize {
+    fn count<const N: usize>(lens: &[usize], k: u128, odd: bool) -> u128 {
+        match lens.split_first() {
+            None => {
+                let c = binomial::<N>(k + N as u128 - 1, N - 1);
+                if odd {
+                    c.wrapping_neg()
+                } else {
+                    c
+                }
+            }
+            Some((&len, rest)) => {
+                let mut c = count::<N>(rest, k, odd);
+                if let Some(k) = k.checked_sub(len as u128) {
+                    c = c.wrapping_add(count::<N>(rest, k, !odd));
+                }
+                c
+            }
+        }
+    }
+
+    if N == 0 {
+        return (k == 0) as usize;
+    }
+    count::<N>(&lens, k as u128, false) as usize
+}
+
+/// `n` choose `r` modulo 2^128, for `r < N`
+fn binomial<const N: usize>(n: u128, r: usize) -> u128 {
+    // cancel the denominator against the numerator first, so the division is exact
+    let mut numerator = [1u128; N];
+    for (i, f) in numerator[..r].iter_mut().enumerate() {
+        *f = n - i as u128;
+    }
+    for d in 2..=r as u128 {
+        let mut d = d;
+        for f in &mut numerator[..r] {
+            let g = gcd(*f, d);
+            *f /= g;
+            d /= g;
+        }
+    }
+    numerator.into_iter().fold(1, u128::wrapping_mul)
+}
+
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+impl<I: Interpolate + Copy, const N: usize> Iterator for GridWavefront<I, N> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.next_index()?;
+        Some(self.interpolate.interpolate(self.flat_index(index)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<I: Interpolate + Copy, const N: usize> DoubleEndedIterator for GridWavefront<I, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let index = self.next_back_index()?;
+        Some(self.interpolate.interpolate(self.flat_index(index)))
+    }
+}
+
+impl<I: Interpolate + Copy, const N: usize> ExactSizeIterator for GridWavefront<I, N> {
+    fn len(&self) -> usize {
+        self.remaining
+    }
+}
+
+impl<I: Interpolate + Copy, const N: usize> FusedIterator for GridWavefront<I, N> {}
+
+/// [`Iterator`] over the wavefronts of a grid, each as a [`GridWavefront`] over just its points.
+/// Created by [`Space::wavefronts`].
+///
+/// The points of a wavefront only depend on points in the wavefronts before it
+/// in dynamic programming over the grid, so each can be handled in parallel.
+#[derive(Clone, Debug)]
+pub struct GridWavefronts<I, const N: usize> {
+    interpolate: I,
+    lens: [usize; N],
+    /// The sum of the indices of the next wavefront from the front
+    front: usize,
+    /// The sum of the indices after the next wavefront from the back
+    back: usize,
+}
+
+impl<I: GridInterpolation<N>, const N: usize> GridWavefronts<I, N> {
+    fn new(interpolate: I) -> Self {
+        let lens = interpolate.lens();
+        let back = match lens.contains(&0) {
+            true => 0,
+            false => lens.iter().map(|len| len - 1).sum::<usize>() + 1,
+        };
+        GridWavefronts {
+            interpolate: interpolate.unordered(),
+            lens,
+            front: 0,
+            back,
+        }
+    }
+}
+
+impl<I: Copy, const N: usize> GridWavefronts<I, N> {
+    fn wavefront(&self, k: usize) -> GridWavefront<I, N> {
+        GridWavefront {
+            interpolate: self.interpolate,
+            lens: self.lens,
+            front: first_on_wavefront(self.lens, k),
+            back: last_on_wavefront(self.lens, k),
+            remaining: wavefront_len(self.lens, k),
+        }
+    }
+}
+
+impl<I: Interpolate + Copy, const N: usize> Iterator for GridWavefronts<I, N> {
+    type Item = GridWavefront<I, N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.front += 1;
+        Some(self.wavefront(self.front - 1))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<I: Interpolate + Copy, const N: usize> DoubleEndedIterator for GridWavefronts<I, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.wavefront(self.back))
+    }
+}
+
+impl<I: Interpolate + Copy, const N: usize> ExactSizeIterator for GridWavefronts<I, N> {
+    fn len(&self) -> usize {
+        self.back - self.front
+    }
+}
+
+impl<I: Interpolate + Copy, const N: usize> FusedIterator for GridWavefronts<I, N> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::odometer::GridIndex;
+
+    #[test]
+    fn test_wavefront_steps() {
+        let lens = [3, 2, 4];
+        let points: Vec<_> = (0..7)
+            .flat_map(|k| {
+                let mut index = Some(first_on_wavefront(lens, k));
+                core::iter::from_fn(move || {
+                    let current = index?;
+                    index = next_on_wavefront(lens, current);
+                    Some(current)
+                })
+            })
+            .collect();
+
+        // every point once, in order of the sum then column-major within it
+        assert_eq!(points.len(), 24);
+        for pair in points.windows(2) {
+            let [a, b] = [pair[0], pair[1]].map(|p| p.iter().sum::<usize>());
+            assert!(a < b || (a == b && pair[0].iter().rev().lt(pair[1].iter().rev())));
+        }
+
+        for k in 0..7 {
+            let wavefront: Vec<_> = points
+                .iter()
+                .filter(|p| p.iter().sum::<usize>() == k)
+                .collect();
+            assert_eq!(wavefront_len(lens, k), wavefront.len());
+            assert_eq!(&last_on_wavefront(lens, k), *wavefront.last().unwrap());
+            for pair in wavefront.windows(2) {
+                assert_eq!(prev_on_wavefront(lens, *pair[1]), Some(*pair[0]));
+            }
+        }
+        assert_eq!(wavefront_len(lens, 7), 0);
+    }
+
+    #[test]
+    #[should_panic = "wavefront order must be set before any values are taken"]
+    fn test_wavefronts_after_next() {
+        let mut it = GridIndex::space([2, 3]);
+        it.next();
+        let _ = it.wavefronts();
+    }
+
+    #[test]
+    fn test_wavefront_len() {
+        assert_eq!(wavefront_len([], 0), 1);
+        assert_eq!(wavefront_len([5], 4), 1);
+        assert_eq!(wavefront_len([5], 5), 0);
+        assert_eq!(
+            (0..9).map(|k| wavefront_len([4, 6], k)).collect::<Vec<_>>(),
+            [1, 2, 3, 4, 4, 4, 3, 2, 1]
+        );
+
+        // the binomials overflow even though the counts fit
+        let long = 1 << 40;
+        assert_eq!(wavefront_len([long, 2, 2, 2, 2], long), 15);
+        assert_eq!(wavefront_len([long, 2, 2, 2, 2], 2), 11);
+        assert_eq!(binomial::<2>(u128::MAX, 1), u128::MAX);
+    }
+}